@@ -36,6 +36,11 @@ class Host(Enum):
     Se = "se"
     Ro = "ro"
     Hu = "hu"
+    Fi = "fi"
+    Gr = "gr"
+    Ie = "ie"
+    Hr = "hr"
+    Dk = "dk"
 
 # Check the number of command-line arguments
 if len(sys.argv) != 2:
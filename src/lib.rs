@@ -53,8 +53,13 @@ async fn main() {
         return;
     }
 
-    let host_arg = args[1].as_str();
-    let host: Host = host_arg.into();
+    let host: Host = match args[1].parse() {
+        Ok(host) => host,
+        Err(err) => {
+            println!("{err}");
+            return;
+        }
+    };
 
     let vinted = VintedWrapper::new_with_host(host);
     println!("Host: {}", vinted.get_host());
@@ -73,10 +78,8 @@ async fn main() {
     let adidas = db.get_brand_by_name(&"Adidas").await.unwrap();
     let nike = db.get_brand_by_name(&"Nike").await.unwrap();
 
-    let brands = format!("{},{}", adidas.id, nike.id);
-
     let filter = Filter::builder()
-        .brand_ids(Some(brands))
+        .brand_ids([&adidas, &nike])
         .price_from(Some(15.0))
         .price_to(Some(20.0))
         .build();
@@ -67,6 +67,22 @@ pub mod payment_method;
 /// - `photo`: The photo of the user.
 pub mod user;
 
+/// The `feedback` module provides the reviews members leave each other after a transaction.
+///
+/// # Structs
+///
+/// - `UserFeedback`: A review, written by a member or left by Vinted.
+/// - `Reputation`: Aggregate of the feedback of a member.
+pub mod feedback;
+
+/// The `exchange` module provides exchange rates to compare prices in different currencies.
+///
+/// # Structs
+///
+/// - `StaticRates`: Fixed rates relative to a base currency.
+/// - `FileRates`: Rates read from a JSON file.
+pub mod exchange;
+
 /// Serde configuration attributes to handle wrongly typed items
 pub mod serde_config;
 
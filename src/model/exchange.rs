@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rust_decimal::Decimal;
+use thiserror::Error;
+
+use crate::model::filter::Currency;
+use crate::model::item::{AdvancedItem, Item, Price};
+use crate::model::{Deserialize, Serialize};
+
+#[derive(Error, Debug)]
+pub enum ExchangeError {
+    #[error("No exchange rate from {0} to {1}")]
+    MissingRate(Currency, Currency),
+    #[error("Converting from {0} to {1} overflows")]
+    Overflow(Currency, Currency),
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    SerdeError(#[from] serde_json::Error),
+}
+
+/// Provides exchange rates between currencies, to compare prices of different markets
+pub trait ExchangeRates {
+    /// Returns the amount of `to` worth one unit of `from`, if known
+    fn rate(&self, from: Currency, to: Currency) -> Option<Decimal>;
+
+    /// Converts `price` to the currency `to`. The amount is not rounded
+    fn convert(&self, price: &Price, to: Currency) -> Result<Price, ExchangeError> {
+        let from = price.currency_code;
+
+        if from == to && from.is_known() {
+            return Ok(price.clone());
+        }
+
+        let rate = self
+            .rate(from, to)
+            .ok_or(ExchangeError::MissingRate(from, to))?;
+
+        let amount = price
+            .amount
+            .checked_mul(rate)
+            .ok_or(ExchangeError::Overflow(from, to))?;
+
+        Ok(Price::new(amount, to))
+    }
+}
+
+/// Fixed exchange rates, given as the amount of each currency worth one unit of a base currency
+///
+///### Example
+///```rust
+/// use rust_decimal::Decimal;
+/// use vinted_rs::model::exchange::{ExchangeRates, StaticRates};
+/// use vinted_rs::model::filter::Currency;
+/// use vinted_rs::model::item::Price;
+///
+/// let rates = StaticRates::new(Currency::EUR)
+///     .with_rate(Currency::GBP, Decimal::new(85, 2))
+///     .with_rate(Currency::PLN, Decimal::new(430, 2));
+///
+/// let price: Price = "8.50 GBP".parse().unwrap();
+///
+/// assert_eq!(rates.convert(&price, Currency::EUR).unwrap(), "10 EUR".parse().unwrap());
+///```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StaticRates {
+    base: Currency,
+    rates: HashMap<Currency, Decimal>,
+}
+
+impl StaticRates {
+    pub fn new(base: Currency) -> Self {
+        StaticRates {
+            base,
+            rates: HashMap::new(),
+        }
+    }
+
+    /// Sets the amount of `currency` worth one unit of the base currency
+    pub fn with_rate(mut self, currency: Currency, per_base: Decimal) -> Self {
+        self.set_rate(currency, per_base);
+        self
+    }
+
+    /// Sets the amount of `currency` worth one unit of the base currency
+    pub fn set_rate(&mut self, currency: Currency, per_base: Decimal) {
+        self.rates.insert(currency, per_base);
+    }
+
+    pub fn base(&self) -> Currency {
+        self.base
+    }
+
+    /// Amount of `currency` worth one unit of the base currency
+    fn per_base(&self, currency: Currency) -> Option<Decimal> {
+        if !currency.is_known() {
+            return None;
+        }
+
+        if currency == self.base {
+            return Some(Decimal::ONE);
+        }
+
+        self.rates
+            .get(&currency)
+            .copied()
+            .filter(|rate| !rate.is_zero())
+    }
+}
+
+impl ExchangeRates for StaticRates {
+    fn rate(&self, from: Currency, to: Currency) -> Option<Decimal> {
+        self.per_base(to)?.checked_div(self.per_base(from)?)
+    }
+}
+
+/// Exchange rates read from a JSON file, that can be reloaded when the file is updated
+///
+/// The file holds a base currency and the amount of each currency worth one unit of it:
+///
+/// ```json
+/// { "base": "EUR", "rates": { "GBP": "0.85", "PLN": 4.30, "USD": 1.08 } }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FileRates {
+    path: PathBuf,
+    rates: StaticRates,
+}
+
+impl FileRates {
+    /// Reads the rates stored at `path`
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ExchangeError> {
+        let path = path.as_ref().to_path_buf();
+        let rates = read_rates(&path)?;
+
+        Ok(FileRates { path, rates })
+    }
+
+    /// Reads the file again, keeping the current rates if it fails
+    pub fn reload(&mut self) -> Result<(), ExchangeError> {
+        self.rates = read_rates(&self.path)?;
+        Ok(())
+    }
+
+    pub fn rates(&self) -> &StaticRates {
+        &self.rates
+    }
+}
+
+impl ExchangeRates for FileRates {
+    fn rate(&self, from: Currency, to: Currency) -> Option<Decimal> {
+        self.rates.rate(from, to)
+    }
+}
+
+fn read_rates(path: &Path) -> Result<StaticRates, ExchangeError> {
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+impl Item {
+    /// Returns the price of the item converted to `currency`
+    pub fn price_in<R: ExchangeRates + ?Sized>(
+        &self,
+        rates: &R,
+        currency: Currency,
+    ) -> Result<Price, ExchangeError> {
+        rates.convert(&self.price, currency)
+    }
+}
+
+impl AdvancedItem {
+    /// Returns the price of the item converted to `currency`
+    pub fn price_in<R: ExchangeRates + ?Sized>(
+        &self,
+        rates: &R,
+        currency: Currency,
+    ) -> Result<Price, ExchangeError> {
+        rates.convert(&self.price(), currency)
+    }
+
+    /// Returns the price paid by the buyer, service fee included, converted to `currency`
+    pub fn total_price_in<R: ExchangeRates + ?Sized>(
+        &self,
+        rates: &R,
+        currency: Currency,
+    ) -> Result<Price, ExchangeError> {
+        let total = self
+            .total_price()
+            .ok_or(ExchangeError::Overflow(self.currency, currency))?;
+
+        rates.convert(&total, currency)
+    }
+}
+
+/// Sorts `items` by price in `currency`, cheapest first. Items without a known rate go last
+///
+///### Example
+///```rust
+/// use vinted_rs::model::exchange::{StaticRates, sort_by_price};
+/// use vinted_rs::model::filter::Currency;
+/// use vinted_rs::model::item::Item;
+///
+/// fn rank(items: &mut Vec<Item>, rates: &StaticRates) {
+///     sort_by_price(items, rates, Currency::EUR);
+/// }
+///```
+pub fn sort_by_price<R: ExchangeRates + ?Sized>(items: &mut [Item], rates: &R, currency: Currency) {
+    items.sort_by_cached_key(|item| {
+        item.price_in(rates, currency)
+            .map(|price| price.amount)
+            .ok()
+            .map_or((1, Decimal::ZERO), |amount| (0, amount))
+    });
+}
@@ -0,0 +1,154 @@
+use chrono::{DateTime, Utc};
+
+use crate::model::items::Pagination;
+use crate::model::photo::Photo;
+use crate::model::serde_config::datetime_from_str_or_int;
+use crate::model::user::UserProfile;
+use crate::model::{Deserialize, Serialize};
+#[cfg(feature = "redis")]
+use crate::model::{FromRedisValue, ToRedisArgs};
+
+/// Review left to a member after a transaction
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "redis", derive(FromRedisValue, ToRedisArgs,))]
+pub struct UserFeedback {
+    pub id: i64,
+    /// Stars given, from 1 to 5
+    pub rating: i32,
+    /// Text of the review
+    #[serde(rename = "feedback", skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// Member who left the review, None for feedback left automatically by Vinted
+    #[serde(rename = "user", skip_serializing_if = "Option::is_none")]
+    pub author: Option<FeedbackAuthor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_title: Option<String>,
+    /// Automatic feedback, left by Vinted when the buyer does not review a completed order
+    #[serde(rename = "system_feedback", default)]
+    pub is_system: bool,
+    #[serde(
+        rename = "created_at_ts",
+        deserialize_with = "datetime_from_str_or_int"
+    )]
+    pub created_at: DateTime<Utc>,
+}
+
+impl UserFeedback {
+    pub fn kind(&self) -> FeedbackKind {
+        if self.is_system || self.author.is_none() {
+            FeedbackKind::System
+        } else {
+            FeedbackKind::Member
+        }
+    }
+}
+
+/// Origin of a [`UserFeedback`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FeedbackKind {
+    /// Written by a member
+    Member,
+    /// Left automatically by Vinted
+    System,
+}
+
+/// Member who wrote a [`UserFeedback`]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct FeedbackAuthor {
+    /// Vinted user ID
+    pub id: i64,
+    /// Username
+    pub login: String,
+    /// User's profile picture URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo: Option<Photo>,
+}
+
+/// Page of feedback returned by [`get_user_feedback`](crate::queries::VintedWrapper::get_user_feedback)
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "redis", derive(FromRedisValue, ToRedisArgs,))]
+pub struct UserFeedbacks {
+    #[serde(rename = "user_feedbacks")]
+    pub feedback: Vec<UserFeedback>,
+    pub pagination: Pagination,
+}
+
+impl UserFeedbacks {
+    /// Summary of the feedback of this page
+    pub fn reputation(&self) -> Reputation {
+        Reputation::from_feedback(&self.feedback)
+    }
+}
+
+/// Aggregate of the feedback of a member
+///
+/// Ratings of 4 and 5 stars are positive, 3 stars neutral and below negative.
+///
+///### Example
+///```rust
+/// use vinted_rs::model::feedback::Reputation;
+/// use vinted_rs::model::user::UserProfile;
+///
+/// fn is_trusted(profile: &UserProfile) -> bool {
+///     let reputation = Reputation::from(profile);
+///     reputation.count >= 10 && reputation.positive_share() > 0.9
+/// }
+///```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Reputation {
+    pub count: i32,
+    pub positive: i32,
+    pub neutral: i32,
+    pub negative: i32,
+    /// Average rating out of 5 stars, 0 without feedback
+    pub average_rating: f32,
+}
+
+impl Reputation {
+    /// Computes the reputation from feedback entries
+    pub fn from_feedback(feedback: &[UserFeedback]) -> Self {
+        let mut reputation = Reputation::default();
+        let mut total = 0;
+
+        for entry in feedback {
+            reputation.count += 1;
+            total += entry.rating;
+
+            match entry.rating {
+                4.. => reputation.positive += 1,
+                3 => reputation.neutral += 1,
+                _ => reputation.negative += 1,
+            }
+        }
+
+        if reputation.count > 0 {
+            reputation.average_rating = total as f32 / reputation.count as f32;
+        }
+
+        reputation
+    }
+
+    /// Share of positive feedback, from 0 to 1
+    pub fn positive_share(&self) -> f32 {
+        if self.count == 0 {
+            return 0.0;
+        }
+
+        self.positive as f32 / self.count as f32
+    }
+}
+
+impl From<&UserProfile> for Reputation {
+    /// Reputation over every feedback of the member, as counted by Vinted
+    fn from(profile: &UserProfile) -> Self {
+        Reputation {
+            count: profile.feedback_count,
+            positive: profile.positive_feedback_count,
+            neutral: profile.neutral_feedback_count,
+            negative: profile.negative_feedback_count,
+            average_rating: profile.rating(),
+        }
+    }
+}
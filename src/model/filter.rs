@@ -1,9 +1,21 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
 use typed_builder::TypedBuilder;
+use url::Url;
+use url::form_urlencoded;
 
+use crate::model::serde_config::{ids_from_string_or_seq, ids_to_string};
 use crate::queries::Host;
+use brand::Brand;
+use category::Category;
+use colors::Color;
+use country::Country;
+use material::Material;
 #[cfg(feature = "redis")]
 use redis_macros::{FromRedisValue, ToRedisArgs};
+use size::Size;
 
 /// Provides functionality related to filtering by brand.
 pub mod brand;
@@ -34,8 +46,8 @@ pub mod size;
 /// use vinted_rs::Filter;
 ///
 /// let filter: Filter = Filter::builder()
-///      .catalog_ids(Some(String::from("4,16")))
-///      .brand_ids(Some(String::from("14,53")))
+///      .catalog_ids([4, 16])
+///      .brand_ids([14, 53])
 ///      .build();
 ///
 /// // Women shoes and Women clothes that are only from brands Adidas and Nike.
@@ -44,7 +56,8 @@ pub mod size;
 ///```
 ///
 ///
-/// `price_from` filter should be always <= `price_to` , otherwise Vinted will not find anything
+/// `price_from` filter should be always <= `price_to` , otherwise Vinted will not find anything.
+/// Use [`Filter::validate`] to check it, [`get_items`](crate::VintedWrapper::get_items) rejects invalid filters.
 ///
 #[derive(TypedBuilder, Debug, Clone, Serialize, Deserialize)]
 pub struct Filter {
@@ -58,37 +71,26 @@ pub struct Filter {
     ///
     #[builder(default)]
     pub search_text: Option<String>,
-    ///
-    ///
-    ///
-    ///
-    ///The catalog IDs to filter items by. Must be formatted as `^[\d+,]*\d+$` regex.
-    ///
-    ///
-    ///If not formated with the specified regex, undefined behaviour. (Input will not be checked).
-    ///
-    ///
-    ///Try it in [Regex 101](https://regex101.com/r/u8ZEpv/1)
+    ///The catalog IDs to filter items by, given as ids or [`Category`] values.
     ///
     ///### Example
     ///```rust
     /// use vinted_rs::Filter;
     ///
     ///
-    /// let filter: Filter = Filter::builder().catalog_ids(Some(String::from("4,16"))).build();
+    /// let filter: Filter = Filter::builder().catalog_ids([4, 16]).build();
     /// // Where 4 and 16 are catalog_ids from Vinted
     /// // 4 is catalog_id for Women clothes
     /// // 16 is catalog_id for Women Shoes
     ///```
-    #[builder(default)]
-    pub catalog_ids: Option<String>,
-    ///The color IDs to filter items by. Must be formatted as `^[\d+,]*\d+$` regex.
-    ///
-    ///If not formated with the specified regex, undefined behaviour. (Input will not be checked).
-    ///
-    ///
-    ///Try it in [Regex 101](https://regex101.com/r/u8ZEpv/1)
-    ///
+    #[builder(default, setter(transform = |ids: impl IntoIterator<Item = impl FilterId>| Some(ids.into_iter().map(|id| id.filter_id()).collect())))]
+    #[serde(
+        default,
+        serialize_with = "ids_to_string",
+        deserialize_with = "ids_from_string_or_seq"
+    )]
+    pub catalog_ids: Option<Vec<i32>>,
+    ///The color IDs to filter items by, given as ids or [`Color`] values.
     ///
     /// **Note:** Color names are only avalible in French in our database at the moment
     ///### Example
@@ -96,89 +98,92 @@ pub struct Filter {
     /// use vinted_rs::Filter;
     ///
     ///
-    /// let filter: Filter = Filter::builder().color_ids(Some(String::from("1,5"))).build();
+    /// let filter: Filter = Filter::builder().color_ids([1, 5]).build();
     /// // Where 1 and 5 are color_ids from Vinted
     /// // 1 is color_id for Black
     /// // 5 is color_id for Pink
     ///```
-    #[builder(default)]
-    pub color_ids: Option<String>,
-    /// The brand IDs to filter items by. Must be formatted as `^[\d+,]*\d+$` regex.
-    ///
-    ///If not formated with the specified regex, undefined behaviour. (Input will not be checked).
-    ///
-    ///
-    ///Try it in [Regex 101](https://regex101.com/r/u8ZEpv/1)
+    #[builder(default, setter(transform = |ids: impl IntoIterator<Item = impl FilterId>| Some(ids.into_iter().map(|id| id.filter_id()).collect())))]
+    #[serde(
+        default,
+        serialize_with = "ids_to_string",
+        deserialize_with = "ids_from_string_or_seq"
+    )]
+    pub color_ids: Option<Vec<i32>>,
+    /// The brand IDs to filter items by, given as ids or [`Brand`] values.
     ///
     ///### Example
     ///```rust
     /// use vinted_rs::Filter;
     ///
     ///
-    /// let filter: Filter = Filter::builder().brand_ids(Some(String::from("14,53"))).build();
+    /// let filter: Filter = Filter::builder().brand_ids([14, 53]).build();
     /// // Where 14 and 53 are brand_ids from Vinted
     /// // 14 is brand_id for Adidas
     /// // 53 is brand_id for Nike
     ///```
-    #[builder(default)]
-    pub brand_ids: Option<String>,
-    /// The country IDs to filter items by. Must be formatted as `^[\d+,]*\d+$` regex.
-    ///
-    ///If not formated with the specified regex, undefined behaviour. (Input will not be checked).
-    ///
-    ///
-    ///Try it in [Regex 101](https://regex101.com/r/u8ZEpv/1)
+    #[builder(default, setter(transform = |ids: impl IntoIterator<Item = impl FilterId>| Some(ids.into_iter().map(|id| id.filter_id()).collect())))]
+    #[serde(
+        default,
+        serialize_with = "ids_to_string",
+        deserialize_with = "ids_from_string_or_seq"
+    )]
+    pub brand_ids: Option<Vec<i32>>,
+    /// The country IDs to filter items by, given as ids or [`Country`] values.
     ///
     ///### Example
     ///```rust
     /// use vinted_rs::Filter;
     ///
     ///
-    /// let filter: Filter = Filter::builder().countries_ids(Some(String::from("7,16"))).build();
+    /// let filter: Filter = Filter::builder().countries_ids([7, 16]).build();
     /// // Where 7 and 16 are country_ids from Vinted
     /// // 7 is country_id for Spain
     /// // 16 is country_id for France
     ///```
-    #[builder(default)]
-    pub countries_ids: Option<String>,
-    /// The size IDs to filter items by. Must be formatted as `^[\d+,]*\d+$` regex.
-    ///
-    ///If not formated with the specified regex, undefined behaviour. (Input will not be checked).
-    ///
-    ///
-    ///Try it in [Regex 101](https://regex101.com/r/u8ZEpv/1)
+    #[builder(default, setter(transform = |ids: impl IntoIterator<Item = impl FilterId>| Some(ids.into_iter().map(|id| id.filter_id()).collect())))]
+    #[serde(
+        default,
+        serialize_with = "ids_to_string",
+        deserialize_with = "ids_from_string_or_seq"
+    )]
+    pub countries_ids: Option<Vec<i32>>,
+    /// The material IDs to filter items by, given as ids or [`Material`] values.
     ///
     ///### Example
     ///```rust
     /// use vinted_rs::Filter;
     ///
     ///
-    /// let filter: Filter = Filter::builder().size_ids(Some(String::from("1226,102"))).build();
-    /// // Where 7 and 16 are country_ids from Vinted
-    /// // 1226 is size_id for XXXS / 30 / 2
-    /// // 102 is size_id for XXS / 32 / 4
+    /// let filter: Filter = Filter::builder().material_ids([44, 49]).build();
+    /// // 44 is material_id for coton
+    /// // 49 is material_id for silk
     ///```
-    #[builder(default)]
-    pub material_ids: Option<String>,
-    /// The material IDs to filter items by. Must be formatted as `^[\d+,]*\d+$` regex.
-    ///
-    ///If not formated with the specified regex, undefined behaviour. (Input will not be checked).
-    ///
-    ///
-    ///Try it in [Regex 101](https://regex101.com/r/u8ZEpv/1)
+    #[builder(default, setter(transform = |ids: impl IntoIterator<Item = impl FilterId>| Some(ids.into_iter().map(|id| id.filter_id()).collect())))]
+    #[serde(
+        default,
+        serialize_with = "ids_to_string",
+        deserialize_with = "ids_from_string_or_seq"
+    )]
+    pub material_ids: Option<Vec<i32>>,
+    /// The size IDs to filter items by, given as ids or [`Size`] values.
     ///
     ///### Example
     ///```rust
     /// use vinted_rs::Filter;
     ///
     ///
-    /// let filter: Filter = Filter::builder().material_ids(Some(String::from("44,102"))).build();
-    /// // Where 7 and 16 are country_ids from Vinted
-    /// // 44 is material_id for coton
-    /// // 49 is material_id for silk
+    /// let filter: Filter = Filter::builder().size_ids([1226, 102]).build();
+    /// // 1226 is size_id for XXXS / 30 / 2
+    /// // 102 is size_id for XXS / 32 / 4
     ///```
-    #[builder(default)]
-    pub size_ids: Option<String>,
+    #[builder(default, setter(transform = |ids: impl IntoIterator<Item = impl FilterId>| Some(ids.into_iter().map(|id| id.filter_id()).collect())))]
+    #[serde(
+        default,
+        serialize_with = "ids_to_string",
+        deserialize_with = "ids_from_string_or_seq"
+    )]
+    pub size_ids: Option<Vec<i32>>,
     /// The article statuses to filter items by.
     ///
     ///### Example
@@ -226,6 +231,379 @@ pub struct Filter {
     #[builder(default)]
     pub price_to: Option<f32>,
 }
+impl Filter {
+    /// Returns the query parameters sent to Vinted for this filter, without encoding
+    ///
+    ///### Example
+    ///```rust
+    /// use vinted_rs::Filter;
+    ///
+    /// let filter: Filter = Filter::builder().search_text(Some(String::from("shoes"))).build();
+    ///
+    /// assert_eq!(filter.query_pairs(), vec![("search_text", String::from("shoes"))]);
+    ///```
+    pub fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = vec![];
+
+        if let Some(text) = &self.search_text {
+            pairs.push(("search_text", text.clone()));
+        }
+
+        let ids = [
+            ("catalog_ids", &self.catalog_ids),
+            ("color_ids", &self.color_ids),
+            ("brand_ids", &self.brand_ids),
+            ("size_ids", &self.size_ids),
+            ("material_ids", &self.material_ids),
+            ("country_ids", &self.countries_ids),
+        ];
+
+        for (name, ids) in ids {
+            if let Some(ids) = ids.as_ref().filter(|ids| !ids.is_empty()) {
+                let ids: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+                pairs.push((name, ids.join(",")));
+            }
+        }
+
+        if let Some(price_from) = self.price_from {
+            pairs.push(("price_from", price_from.to_string()));
+        }
+
+        if let Some(price_to) = self.price_to {
+            pairs.push(("price_to", price_to.to_string()));
+        }
+
+        if let Some(article_status) = &self.article_status {
+            let status_ids: Vec<&str> = article_status.iter().map(|status| status.into()).collect();
+            pairs.push(("status_ids", status_ids.join(",")));
+        }
+
+        if let Some(sort_by) = &self.sort_by {
+            let sort_by: &str = sort_by.into();
+            pairs.push(("order", sort_by.to_string()));
+        }
+
+        pairs
+    }
+
+    /// Checks the filter before sending it, returning every problem found
+    ///
+    /// Prices must be finite and non-negative with `price_from <= price_to`, ids must be positive and id lists
+    /// must not be empty.
+    ///
+    ///### Example
+    ///```rust
+    /// use vinted_rs::Filter;
+    /// use vinted_rs::model::filter::FilterProblem;
+    ///
+    /// let filter: Filter = Filter::builder()
+    ///     .price_from(Some(20.0))
+    ///     .price_to(Some(-10.0))
+    ///     .build();
+    ///
+    /// let err = filter.validate().unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     err.problems,
+    ///     vec![
+    ///         FilterProblem::NegativePrice("price_to", -10.0),
+    ///         FilterProblem::PriceRange(20.0, -10.0),
+    ///     ]
+    /// );
+    ///```
+    pub fn validate(&self) -> Result<(), FilterValidationError> {
+        let mut problems = vec![];
+
+        let ids = [
+            ("catalog_ids", &self.catalog_ids),
+            ("color_ids", &self.color_ids),
+            ("brand_ids", &self.brand_ids),
+            ("size_ids", &self.size_ids),
+            ("material_ids", &self.material_ids),
+            ("country_ids", &self.countries_ids),
+        ];
+
+        for (name, ids) in ids {
+            let Some(ids) = ids else {
+                continue;
+            };
+
+            if ids.is_empty() {
+                problems.push(FilterProblem::EmptyIds(name));
+            }
+
+            for id in ids.iter().filter(|id| **id <= 0) {
+                problems.push(FilterProblem::InvalidId(name, *id));
+            }
+        }
+
+        let prices = [("price_from", self.price_from), ("price_to", self.price_to)];
+
+        for (name, price) in prices {
+            match price {
+                Some(price) if !price.is_finite() => {
+                    problems.push(FilterProblem::NonFinitePrice(name, price))
+                }
+                Some(price) if price < 0.0 => {
+                    problems.push(FilterProblem::NegativePrice(name, price))
+                }
+                _ => {}
+            }
+        }
+
+        if let (Some(price_from), Some(price_to)) = (self.price_from, self.price_to) {
+            if price_from > price_to {
+                problems.push(FilterProblem::PriceRange(price_from, price_to));
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(FilterValidationError { problems })
+        }
+    }
+
+    /// Returns the URL encoded query string of the filter, as sent to Vinted
+    ///
+    ///### Example
+    ///```rust
+    /// use vinted_rs::Filter;
+    ///
+    /// let filter: Filter = Filter::builder()
+    ///     .search_text(Some(String::from("Zapatillas & botas")))
+    ///     .build();
+    ///
+    /// assert_eq!(filter.to_query_string(), "search_text=Zapatillas+%26+botas");
+    ///```
+    pub fn to_query_string(&self) -> String {
+        form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(self.query_pairs())
+            .finish()
+    }
+}
+
+/// Problem found by [`Filter::validate`]
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum FilterProblem {
+    #[error("`{0}` must be a finite number, got {1}")]
+    NonFinitePrice(&'static str, f32),
+    #[error("`{0}` must not be negative, got {1}")]
+    NegativePrice(&'static str, f32),
+    #[error("`price_from` ({0}) must be lower or equal than `price_to` ({1})")]
+    PriceRange(f32, f32),
+    #[error("`{0}` contains the invalid id {1}, ids must be positive")]
+    InvalidId(&'static str, i32),
+    #[error("`{0}` is empty, leave it unset to not filter by it")]
+    EmptyIds(&'static str),
+}
+
+/// Error returned by [`Filter::validate`], listing every problem of the filter
+#[derive(Error, Debug, Clone, PartialEq)]
+pub struct FilterValidationError {
+    pub problems: Vec<FilterProblem>,
+}
+
+impl fmt::Display for FilterValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let problems: Vec<String> = self
+            .problems
+            .iter()
+            .map(|problem| problem.to_string())
+            .collect();
+
+        write!(f, "Invalid filter: {}", problems.join("; "))
+    }
+}
+
+/// Value that can be used as an id in the [`Filter`] id lists
+///
+/// Implemented for raw ids and the reference data models, so both can be given to the builder.
+///
+///### Example
+///```rust
+/// use vinted_rs::Filter;
+/// use vinted_rs::model::filter::brand::Brand;
+///
+/// let nike = Brand::builder()
+///     .id(53)
+///     .title(String::from("Nike"))
+///     .url(String::from("https://www.vinted.fr/brand/53-nike"))
+///     .build();
+///
+/// let filter: Filter = Filter::builder().brand_ids([&nike]).catalog_ids(vec![4, 16]).build();
+///
+/// assert_eq!(filter.brand_ids, Some(vec![53]));
+///```
+pub trait FilterId {
+    /// Vinted id of the value
+    fn filter_id(&self) -> i32;
+}
+
+impl FilterId for i32 {
+    fn filter_id(&self) -> i32 {
+        *self
+    }
+}
+
+impl<T: FilterId + ?Sized> FilterId for &T {
+    fn filter_id(&self) -> i32 {
+        (**self).filter_id()
+    }
+}
+
+macro_rules! impl_filter_id {
+    ($($model:ty),*) => {
+        $(
+            impl FilterId for $model {
+                fn filter_id(&self) -> i32 {
+                    self.id
+                }
+            }
+        )*
+    };
+}
+
+impl_filter_id!(Brand, Category, Color, Country, Material, Size);
+
+/// Error returned when a Vinted catalog URL can not be turned into a [`Filter`]
+#[derive(Error, Debug)]
+pub enum FilterUrlError {
+    #[error(transparent)]
+    UrlError(#[from] url::ParseError),
+    #[error("`{0}` is not a Vinted domain")]
+    HostError(String),
+    #[error("Invalid value `{1}` for parameter `{0}`")]
+    ParameterError(String, String),
+}
+
+impl Filter {
+    /// Parses a catalog URL copied from the browser, returning its filter and the Host of its domain
+    ///
+    /// Both the `brand_ids[]=53&brand_ids[]=14` and the `brand_ids=53,14` forms are understood. Parameters that are
+    /// not filters, like `page` or `currency`, are ignored.
+    ///
+    ///### Example
+    ///```rust
+    /// use vinted_rs::Filter;
+    /// use vinted_rs::model::filter::SortBy;
+    ///
+    /// let (filter, host) = Filter::from_url(
+    ///     "https://www.vinted.fr/catalog?search_text=nike&brand_ids[]=53&price_to=20&order=newest_first",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(filter.brand_ids, Some(vec![53]));
+    /// assert_eq!(filter.sort_by, Some(SortBy::NewestFirst));
+    /// assert_eq!(<&str>::from(host), "fr");
+    ///```
+    pub fn from_url(url: &str) -> Result<(Filter, Host), FilterUrlError> {
+        let url = Url::parse(url)?;
+        let domain = url.host_str().unwrap_or_default();
+        let host = Host::from_domain(domain)
+            .ok_or_else(|| FilterUrlError::HostError(domain.to_string()))?;
+
+        Ok((Filter::try_from(url)?, host))
+    }
+}
+
+impl TryFrom<Url> for Filter {
+    type Error = FilterUrlError;
+
+    /// Parses the filter parameters of a catalog URL, whatever its domain
+    fn try_from(url: Url) -> Result<Self, Self::Error> {
+        let mut filter = Filter::builder().build();
+
+        for (name, value) in url.query_pairs() {
+            let name = name.trim_end_matches("[]");
+            let value = value.trim();
+
+            if value.is_empty() {
+                continue;
+            }
+
+            match name {
+                "search_text" => filter.search_text = Some(value.to_string()),
+                "catalog" | "catalog_ids" => push_ids(&mut filter.catalog_ids, name, value)?,
+                "color_ids" => push_ids(&mut filter.color_ids, name, value)?,
+                "brand_ids" => push_ids(&mut filter.brand_ids, name, value)?,
+                "size_ids" => push_ids(&mut filter.size_ids, name, value)?,
+                "material_ids" => push_ids(&mut filter.material_ids, name, value)?,
+                "country_ids" | "countries_ids" => {
+                    push_ids(&mut filter.countries_ids, name, value)?
+                }
+                "status" | "status_ids" => {
+                    let statuses = filter.article_status.get_or_insert_with(Vec::new);
+
+                    for id in value.split(',') {
+                        let status = id
+                            .trim()
+                            .parse::<i32>()
+                            .ok()
+                            .and_then(|id| ArticleStatus::try_from(id).ok())
+                            .ok_or_else(|| parameter_error(name, value))?;
+
+                        if !statuses.contains(&status) {
+                            statuses.push(status);
+                        }
+                    }
+                }
+                "order" => {
+                    filter.sort_by = Some(
+                        sort_by_from_order(value).ok_or_else(|| parameter_error(name, value))?,
+                    )
+                }
+                "price_from" => filter.price_from = Some(parse_price(name, value)?),
+                "price_to" => filter.price_to = Some(parse_price(name, value)?),
+                _ => {}
+            }
+        }
+
+        Ok(filter)
+    }
+}
+
+fn parameter_error(name: &str, value: &str) -> FilterUrlError {
+    FilterUrlError::ParameterError(name.to_string(), value.to_string())
+}
+
+/// Appends the comma separated `value` ids to `ids`
+fn push_ids(ids: &mut Option<Vec<i32>>, name: &str, value: &str) -> Result<(), FilterUrlError> {
+    let ids = ids.get_or_insert_with(Vec::new);
+
+    for id in value.split(',') {
+        let id = id
+            .trim()
+            .parse::<i32>()
+            .map_err(|_| parameter_error(name, value))?;
+
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_price(name: &str, value: &str) -> Result<f32, FilterUrlError> {
+    value
+        .parse::<f32>()
+        .ok()
+        .filter(|price| price.is_finite())
+        .ok_or_else(|| parameter_error(name, value))
+}
+
+fn sort_by_from_order(order: &str) -> Option<SortBy> {
+    [
+        SortBy::Relevance,
+        SortBy::PriceDescendant,
+        SortBy::PriceAscendant,
+        SortBy::NewestFirst,
+    ]
+    .into_iter()
+    .find(|sort_by| <&str>::from(sort_by) == order)
+}
+
 /**
 Represents the currency for filtering items
 
@@ -238,7 +616,8 @@ Represents the currency for filtering items
 // SEK => Host se
 // RON => Host ro
 // HUF => Host hu
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+// DKK => Host dk
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "redis", derive(FromRedisValue, ToRedisArgs))]
 pub enum Currency {
     /// Euro
@@ -257,6 +636,19 @@ pub enum Currency {
     RON,
     /// Hungarian forint
     HUF,
+    /// Danish krone
+    DKK,
+    /// Currency code not known by this crate, so a single listing in a new currency does not
+    /// fail a whole page. Two unknown currencies may differ and are never treated as the same
+    #[serde(other)]
+    Unknown,
+}
+
+impl Currency {
+    /// Returns false for [`Currency::Unknown`]
+    pub fn is_known(&self) -> bool {
+        *self != Currency::Unknown
+    }
 }
 
 impl From<Currency> for Host {
@@ -269,11 +661,46 @@ impl From<Currency> for Host {
             Currency::SEK => Host::Se,
             Currency::RON => Host::Ro,
             Currency::HUF => Host::Hu,
+            Currency::DKK => Host::Dk,
             Currency::EUR => Host::random_euro_host(),
+            Currency::Unknown => Host::random(),
         }
     }
 }
 
+/// Error returned when a string is not a known currency code
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("`{0}` is not a supported currency")]
+pub struct ParseCurrencyError(String);
+
+impl FromStr for Currency {
+    type Err = ParseCurrencyError;
+
+    /// Parses an ISO 4217 currency code, ignoring case
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        [
+            Currency::EUR,
+            Currency::USD,
+            Currency::GBP,
+            Currency::CZK,
+            Currency::PLN,
+            Currency::SEK,
+            Currency::RON,
+            Currency::HUF,
+            Currency::DKK,
+        ]
+        .into_iter()
+        .find(|currency| <&str>::from(*currency).eq_ignore_ascii_case(string.trim()))
+        .ok_or_else(|| ParseCurrencyError(string.to_string()))
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", <&str>::from(*self))
+    }
+}
+
 impl From<Currency> for &str {
     fn from(currency: Currency) -> Self {
         match currency {
@@ -284,7 +711,9 @@ impl From<Currency> for &str {
             Currency::SEK => "SEK",
             Currency::RON => "RON",
             Currency::HUF => "HUF",
+            Currency::DKK => "DKK",
             Currency::EUR => "EUR",
+            Currency::Unknown => "XXX",
         }
     }
 }
@@ -320,6 +749,27 @@ impl From<&ArticleStatus> for &str {
         }
     }
 }
+/// Error returned when an id is not a known [`ArticleStatus`]
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("`{0}` is not a known article status id")]
+pub struct UnknownArticleStatus(pub i32);
+
+impl TryFrom<i32> for ArticleStatus {
+    type Error = UnknownArticleStatus;
+
+    /// From the Vinted status id, as in [`AdvancedItem::status_id`](crate::model::item::AdvancedItem::status_id)
+    fn try_from(id: i32) -> Result<Self, Self::Error> {
+        match id {
+            6 => Ok(ArticleStatus::NewTags),
+            1 => Ok(ArticleStatus::NewNoTags),
+            2 => Ok(ArticleStatus::VeryGood),
+            3 => Ok(ArticleStatus::Good),
+            4 => Ok(ArticleStatus::Satisfactory),
+            _ => Err(UnknownArticleStatus(id)),
+        }
+    }
+}
+
 /**
 Represents the sort order for the retrieved items.
 */
@@ -1,10 +1,18 @@
 use super::{photo::Photo, user::AdvancedUser};
-use crate::model::serde_config::bool_from_int_or_bool;
+use crate::model::filter::{ArticleStatus, Currency, ParseCurrencyError, UnknownArticleStatus};
+use crate::model::serde_config::{
+    bool_from_int_or_bool, datetime_from_str_or_int, option_datetime_from_str_or_int,
+};
 use crate::model::{Deserialize, Serialize};
 #[cfg(feature = "redis")]
 use crate::model::{FromRedisValue, ToRedisArgs};
 use crate::utils::display_option;
+use chrono::{DateTime, TimeDelta, Utc};
+use rust_decimal::Decimal;
+use std::cmp::Ordering;
 use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Hash, Eq)]
 #[cfg_attr(feature = "redis", derive(FromRedisValue, ToRedisArgs,))]
@@ -21,10 +29,116 @@ pub struct Item {
     pub favourite_count: i32,
 }
 
+/// Amount of money in a [`Currency`]
+///
+/// Prices in different currencies can not be compared nor added, convert them first with
+/// [`ExchangeRates`](crate::model::exchange::ExchangeRates).
+///
+///### Example
+///```rust
+/// use rust_decimal::Decimal;
+/// use vinted_rs::model::filter::Currency;
+/// use vinted_rs::model::item::Price;
+///
+/// let price: Price = "12.50 EUR".parse().unwrap();
+/// let fee = Price::new(Decimal::new(125, 2), Currency::EUR);
+///
+/// assert_eq!(price.checked_add(&fee).unwrap().to_string(), "13.75 EUR");
+/// assert!(price > fee);
+///```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Hash, Eq)]
 pub struct Price {
-    pub amount: String,
-    pub currency_code: String,
+    pub amount: Decimal,
+    pub currency_code: Currency,
+}
+
+/// Error returned when parsing a [`Price`] from a string
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ParsePriceError {
+    #[error("Price `{0}` must be an amount followed by a currency code, e.g. `12.50 EUR`")]
+    FormatError(String),
+    #[error(transparent)]
+    AmountError(#[from] rust_decimal::Error),
+    #[error(transparent)]
+    CurrencyError(#[from] ParseCurrencyError),
+}
+
+impl Price {
+    pub fn new(amount: Decimal, currency_code: Currency) -> Self {
+        Price {
+            amount,
+            currency_code,
+        }
+    }
+
+    /// Adds `other`, returns None if it is in another currency or on overflow
+    pub fn checked_add(&self, other: &Price) -> Option<Price> {
+        if !self.same_currency(other) {
+            return None;
+        }
+
+        Some(Price::new(
+            self.amount.checked_add(other.amount)?,
+            self.currency_code,
+        ))
+    }
+
+    /// Subtracts `other`, returns None if it is in another currency or on overflow
+    pub fn checked_sub(&self, other: &Price) -> Option<Price> {
+        if !self.same_currency(other) {
+            return None;
+        }
+
+        Some(Price::new(
+            self.amount.checked_sub(other.amount)?,
+            self.currency_code,
+        ))
+    }
+
+    /// Multiplies the amount by `factor`, returns None on overflow
+    pub fn checked_mul(&self, factor: Decimal) -> Option<Price> {
+        Some(Price::new(
+            self.amount.checked_mul(factor)?,
+            self.currency_code,
+        ))
+    }
+
+    fn same_currency(&self, other: &Price) -> bool {
+        self.currency_code == other.currency_code && self.currency_code.is_known()
+    }
+}
+
+impl PartialOrd for Price {
+    /// Prices are only comparable in the same currency
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if !self.same_currency(other) {
+            return None;
+        }
+
+        self.amount.partial_cmp(&other.amount)
+    }
+}
+
+impl FromStr for Price {
+    type Err = ParsePriceError;
+
+    /// Parses an amount followed by its currency code, like `12.50 EUR`
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut parts = string.split_whitespace();
+
+        let (Some(amount), Some(currency), None) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(ParsePriceError::FormatError(string.to_string()));
+        };
+
+        Ok(Price::new(amount.parse()?, currency.parse()?))
+    }
+}
+
+impl fmt::Display for Price {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.amount, self.currency_code)
+    }
 }
 
 impl fmt::Display for Item {
@@ -33,11 +147,7 @@ impl fmt::Display for Item {
         writeln!(f, "Title: {}", self.title)?;
         writeln!(f, "Size Title: {}", self.size_title)?;
         writeln!(f, "Brand Title: {}", self.brand_title)?;
-        writeln!(
-            f,
-            "Price: {} {}",
-            self.price.amount, self.price.currency_code
-        )?;
+        writeln!(f, "Price: {}", self.price)?;
         if let Some(ph) = &self.photo {
             writeln!(f, "Photo: {}", ph)?;
         }
@@ -93,6 +203,7 @@ pub struct AdvancedItem {
     pub color2_id: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub color2: Option<String>,
+    /// Parcel size id given by Vinted, its values are not documented
     pub package_size_id: i32,
     /// See [`Country`](crate::model::filter::country::Country)
     pub country_id: i32,
@@ -110,28 +221,42 @@ pub struct AdvancedItem {
     pub view_count: i32,
     /// Moderation status of the item (Vinted internal use only)
     pub moderation_status: i32,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_push_up_at: Option<String>,
+    /// Last time the item was bumped to the top of the listings
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "option_datetime_from_str_or_int",
+        default
+    )]
+    pub last_push_up_at: Option<DateTime<Utc>>,
     /// Related ['Brand'](crate::model::filter::brand::Brand) IDs
     pub related_catalog_ids: Vec<i32>,
 
     // Pricing
     /// Original price of the item
-    pub original_price_numeric: String,
-    /// Currency the item was posted with. See [`Currency`](crate::model::filter::Currency) for valid currencies on Vinted
-    pub currency: String,
+    pub original_price_numeric: Decimal,
+    /// Currency the item was posted with
+    pub currency: Currency,
     /// Current valid price of the item
-    pub price_numeric: String,
-
+    pub price_numeric: Decimal,
+    /// Buyer protection fee, in the currency of the item
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub service_fee: Option<String>,
+    pub service_fee: Option<Decimal>,
 
     // Order by stats
-    pub created_at_ts: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at_ts: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_updated_at_ts: Option<String>,
+    #[serde(deserialize_with = "datetime_from_str_or_int")]
+    pub created_at_ts: DateTime<Utc>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "option_datetime_from_str_or_int",
+        default
+    )]
+    pub updated_at_ts: Option<DateTime<Utc>>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "option_datetime_from_str_or_int",
+        default
+    )]
+    pub user_updated_at_ts: Option<DateTime<Utc>>,
 
     // Assets
     pub photos: Vec<Photo>,
@@ -252,6 +377,96 @@ pub struct AdvancedItem {
     pub can_be_sold: Option<bool>,
 }
 
+/// Availability of a listing, see [`VintedWrapper::check_item_status`](crate::queries::VintedWrapper::check_item_status)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ItemStatus {
+    /// Can be bought
+    Active,
+    /// Reserved by the seller for a buyer
+    Reserved,
+    /// Hidden by the seller or the moderation
+    Hidden,
+    /// Sold or closed by the seller
+    Closed,
+    /// Removed, Vinted answers with `404 Not Found`
+    Deleted,
+}
+
+impl ItemStatus {
+    /// Returns true if the item can be bought
+    pub fn is_available(&self) -> bool {
+        *self == ItemStatus::Active
+    }
+}
+
+impl AdvancedItem {
+    /// Condition of the item, None if it is missing. An id that is not a known [`ArticleStatus`] is returned in the
+    /// error
+    pub fn article_status(&self) -> Option<Result<ArticleStatus, UnknownArticleStatus>> {
+        self.status_id.map(ArticleStatus::try_from)
+    }
+
+    /// Availability of the listing, from its closed, hidden and reserved flags
+    pub fn item_status(&self) -> ItemStatus {
+        let flag = |flag: Option<bool>| flag.unwrap_or(false);
+
+        if flag(self.is_closed) {
+            ItemStatus::Closed
+        } else if flag(self.is_hidden) {
+            ItemStatus::Hidden
+        } else if flag(self.is_reserved) {
+            ItemStatus::Reserved
+        } else {
+            ItemStatus::Active
+        }
+    }
+
+    /// Current valid price of the item
+    pub fn price(&self) -> Price {
+        Price::new(self.price_numeric, self.currency)
+    }
+
+    /// Original price of the item
+    pub fn original_price(&self) -> Price {
+        Price::new(self.original_price_numeric, self.currency)
+    }
+
+    /// Buyer protection fee of the item, if known
+    pub fn service_fee(&self) -> Option<Price> {
+        self.service_fee
+            .map(|service_fee| Price::new(service_fee, self.currency))
+    }
+
+    /// Time elapsed since the item was posted
+    pub fn age(&self) -> TimeDelta {
+        Utc::now() - self.created_at_ts
+    }
+
+    /// Last time the item was shown as new, i.e. when it was posted or bumped
+    pub fn listed_at(&self) -> DateTime<Utc> {
+        self.last_push_up_at
+            .map_or(self.created_at_ts, |pushed_up| {
+                pushed_up.max(self.created_at_ts)
+            })
+    }
+
+    /// Returns true if the item was bumped after being posted
+    pub fn is_bumped(&self) -> bool {
+        self.last_push_up_at
+            .is_some_and(|pushed_up| pushed_up > self.created_at_ts)
+    }
+
+    /// Price paid by the buyer, service fee included. Returns None on overflow
+    pub fn total_price(&self) -> Option<Price> {
+        let service_fee = self.service_fee.unwrap_or_default();
+
+        Some(Price::new(
+            self.price_numeric.checked_add(service_fee)?,
+            self.currency,
+        ))
+    }
+}
+
 impl fmt::Display for AdvancedItem {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "ID: {}", self.id)?;
@@ -283,12 +498,8 @@ impl fmt::Display for AdvancedItem {
         writeln!(f, "View Count: {}", self.view_count)?;
         writeln!(f, "Moderation Status: {}", self.moderation_status)?;
         writeln!(f, "Related Catalog IDs: {:?}", self.related_catalog_ids)?;
-        writeln!(
-            f,
-            "Original Price: {} {}",
-            self.original_price_numeric, self.currency
-        )?;
-        writeln!(f, "Price: {} {}", self.price_numeric, self.currency)?;
+        writeln!(f, "Original Price: {}", self.original_price())?;
+        writeln!(f, "Price: {}", self.price())?;
 
         writeln!(f, "\nFlags: {{")?;
         writeln!(f, "  is_for_sell: {}", display_option(self.is_for_sell))?;
@@ -1,6 +1,9 @@
 use std::fmt;
 
+use chrono::{DateTime, Utc};
+
 use crate::model::item::Item;
+use crate::model::serde_config::datetime_from_str_or_int;
 use crate::model::{Deserialize, Serialize};
 
 #[cfg(feature = "redis")]
@@ -42,8 +45,9 @@ pub struct Pagination {
     pub total_pages: i32,
     pub total_entries: i32,
     pub per_page: i32,
-    #[serde(rename = "time")]
-    pub timestamp: u32,
+    /// Time the results were computed by Vinted
+    #[serde(rename = "time", deserialize_with = "datetime_from_str_or_int")]
+    pub timestamp: DateTime<Utc>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
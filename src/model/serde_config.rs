@@ -1,4 +1,5 @@
-use serde::{Deserialize, Deserializer};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Deserializer, Serializer};
 use serde_json::Value;
 
 pub fn bool_from_int_or_bool<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
@@ -29,3 +30,112 @@ where
         )),
     }
 }
+
+/// Serializes a list of ids as the comma separated string sent to Vinted
+pub fn ids_to_string<S>(ids: &Option<Vec<i32>>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match ids {
+        Some(ids) => {
+            let ids: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+            serializer.serialize_some(&ids.join(","))
+        }
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Deserializes a list of ids from a comma separated string or a sequence of integers
+pub fn ids_from_string_or_seq<'de, D>(deserializer: D) -> Result<Option<Vec<i32>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let parse_id = |value: &Value| match value {
+        Value::Number(n) => n.as_i64().and_then(|id| i32::try_from(id).ok()),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    };
+
+    match Value::deserialize(deserializer)? {
+        Value::String(s) if s.trim().is_empty() => Ok(None),
+        Value::String(s) => s
+            .split(',')
+            .map(|id| parse_id(&Value::String(id.to_string())))
+            .collect::<Option<Vec<i32>>>()
+            .map(Some)
+            .ok_or_else(|| serde::de::Error::custom("expected comma separated integer ids")),
+        Value::Array(values) => values
+            .iter()
+            .map(parse_id)
+            .collect::<Option<Vec<i32>>>()
+            .map(Some)
+            .ok_or_else(|| serde::de::Error::custom("expected a list of integer ids")),
+        Value::Null => Ok(None),
+        _ => Err(serde::de::Error::custom(
+            "expected a string, a list of ids or null for ids field",
+        )),
+    }
+}
+
+/// Parses a date-time given as RFC 3339, as `%Y-%m-%d %H:%M:%S` in UTC or as a Unix timestamp in seconds or milliseconds
+fn datetime_from_value(value: &Value) -> Option<DateTime<Utc>> {
+    match value {
+        Value::Number(n) => datetime_from_timestamp(n.as_i64()?),
+        Value::String(s) => {
+            let s = s.trim();
+
+            if let Ok(datetime) = DateTime::parse_from_rfc3339(s) {
+                return Some(datetime.with_timezone(&Utc));
+            }
+
+            if let Ok(timestamp) = s.parse::<i64>() {
+                return datetime_from_timestamp(timestamp);
+            }
+
+            ["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S"]
+                .iter()
+                .find_map(|format| NaiveDateTime::parse_from_str(s, format).ok())
+                .map(|datetime| datetime.and_utc())
+        }
+        _ => None,
+    }
+}
+
+fn datetime_from_timestamp(timestamp: i64) -> Option<DateTime<Utc>> {
+    // Timestamps in milliseconds have more than 11 digits until year 5138
+    if timestamp.abs() >= 100_000_000_000 {
+        DateTime::from_timestamp_millis(timestamp)
+    } else {
+        DateTime::from_timestamp(timestamp, 0)
+    }
+}
+
+pub fn datetime_from_str_or_int<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Value::deserialize(deserializer)?;
+
+    datetime_from_value(&value).ok_or_else(|| {
+        serde::de::Error::custom(format!(
+            "expected a RFC 3339 date-time or a Unix timestamp, got {value}"
+        ))
+    })
+}
+
+pub fn option_datetime_from_str_or_int<'de, D>(
+    deserializer: D,
+) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Value::deserialize(deserializer)? {
+        Value::Null => Ok(None),
+        Value::String(s) if s.trim().is_empty() => Ok(None),
+        value => datetime_from_value(&value).map(Some).ok_or_else(|| {
+            serde::de::Error::custom(format!(
+                "expected a RFC 3339 date-time, a Unix timestamp or null, got {value}"
+            ))
+        }),
+    }
+}
@@ -1,6 +1,8 @@
+use crate::model::serde_config::option_datetime_from_str_or_int;
 use crate::model::{Deserialize, Serialize};
 #[cfg(feature = "redis")]
 use crate::model::{FromRedisValue, ToRedisArgs};
+use chrono::{DateTime, Utc};
 use std::fmt;
 
 use super::{payment_method::PayInMethod, photo::Photo};
@@ -38,8 +40,12 @@ pub struct AdvancedUser {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gender: Option<String>,
     /// Last time the user logged in
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_loged_on_ts: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "option_datetime_from_str_or_int",
+        default
+    )]
+    pub last_loged_on_ts: Option<DateTime<Utc>>,
     /// If the user wants to expose his location
     pub expose_location: bool,
     /// See [`Country`](crate::model::filter::country::Country)
@@ -78,3 +84,106 @@ impl fmt::Display for AdvancedUser {
         Ok(())
     }
 }
+
+/// Public profile of a member, as shown on their page
+///
+///### Example
+///```rust
+/// use vinted_rs::model::user::UserProfile;
+///
+/// fn describe(profile: &UserProfile) -> String {
+///     format!(
+///         "{}: {:.1}/5 from {} reviews, {} items",
+///         profile.login,
+///         profile.rating(),
+///         profile.feedback_count,
+///         profile.item_count
+///     )
+/// }
+///```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "redis", derive(FromRedisValue, ToRedisArgs,))]
+pub struct UserProfile {
+    /// Vinted user ID
+    pub id: i64,
+    /// Username
+    pub login: String,
+    /// User's profile picture URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo: Option<Photo>,
+    /// Share of positive feedback, from 0 to 1
+    #[serde(default)]
+    pub feedback_reputation: f32,
+    #[serde(default)]
+    pub feedback_count: i32,
+    #[serde(default)]
+    pub positive_feedback_count: i32,
+    #[serde(default)]
+    pub neutral_feedback_count: i32,
+    #[serde(default)]
+    pub negative_feedback_count: i32,
+    /// Items listed in the wardrobe
+    #[serde(default)]
+    pub item_count: i32,
+    #[serde(default)]
+    pub followers_count: i32,
+    #[serde(default)]
+    pub following_count: i32,
+    /// See [`Country`](crate::model::filter::country::Country)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_title: Option<String>,
+    /// City name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(default)]
+    pub is_online: bool,
+    /// Business accounts are professional sellers
+    #[serde(default)]
+    pub business: bool,
+    /// Last time the user logged in
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "option_datetime_from_str_or_int",
+        default
+    )]
+    pub last_loged_on_ts: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub verification: Verification,
+}
+
+impl UserProfile {
+    /// Rating out of 5 stars, computed from the feedback reputation
+    pub fn rating(&self) -> f32 {
+        self.feedback_reputation * 5.0
+    }
+}
+
+/// Accounts linked and verified by a member
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Verification {
+    #[serde(default)]
+    pub email: VerificationMethod,
+    #[serde(default)]
+    pub phone: VerificationMethod,
+    #[serde(default)]
+    pub google: VerificationMethod,
+    #[serde(default)]
+    pub facebook: VerificationMethod,
+}
+
+impl Verification {
+    /// Returns true if at least one method is verified
+    pub fn is_verified(&self) -> bool {
+        [&self.email, &self.phone, &self.google, &self.facebook]
+            .iter()
+            .any(|method| method.valid)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct VerificationMethod {
+    #[serde(default)]
+    pub valid: bool,
+}
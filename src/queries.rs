@@ -36,23 +36,54 @@ use log::error;
 use rand::Rng;
 use reqwest::Client;
 use reqwest::Proxy;
+use reqwest::RequestBuilder;
 use reqwest::Response;
 use reqwest::StatusCode;
 use reqwest_cookie_store::CookieStore;
 use reqwest_cookie_store::CookieStoreMutex;
+use std::collections::HashMap;
 use std::fmt;
+use std::str::FromStr;
 use std::sync::Arc;
+use std::sync::Mutex;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
+use std::time::Duration;
 
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use thiserror::Error;
+use typed_builder::TypedBuilder;
+use url::form_urlencoded;
 
 use crate::model::filter::Currency;
 use crate::model::filter::Filter;
+use crate::model::filter::FilterValidationError;
 use crate::model::item::AdvancedItem;
 use crate::model::items::AdvancedItems;
 use crate::model::items::Items;
 
+mod catalog;
+mod fan_out;
+mod pagination;
+mod rate_limiter;
+mod related;
+pub(crate) mod retry;
+mod scheduler;
+mod status;
+mod users;
+mod watcher;
+
+pub use catalog::Catalog;
+pub use fan_out::{FanOutItems, FanOutOptions, HostItem};
+pub use rate_limiter::{Quota, RateLimiter};
+pub use retry::RetryPolicy;
+use scheduler::Scheduler;
+pub use scheduler::{HostHealth, SchedulingStrategy};
+pub use status::{StatusChange, StatusReport, StatusTracker};
+pub use users::WardrobeOptions;
+pub use watcher::{MemorySeenStore, SeenStore, Watcher};
+
 #[derive(Error, Debug)]
 pub enum CookieError {
     #[error(transparent)]
@@ -73,6 +104,25 @@ pub enum VintedWrapperError {
     ItemNumberError,
     #[error("Could not get deatiled info for item `{2}` with code: {0}")]
     ItemError(StatusCode, Option<i32>, String),
+    #[error(transparent)]
+    FilterError(#[from] FilterValidationError),
+    #[error("There are no wrappers to send the request to")]
+    EmptyWrappersError,
+    #[error("There is no wrapper for host `{0}`")]
+    MissingHostError(Host),
+}
+
+impl VintedWrapperError {
+    /// Returns true if the error comes from the host, so another host or source may succeed
+    pub fn is_host_failure(&self) -> bool {
+        match self {
+            VintedWrapperError::ReqWestError(_)
+            | VintedWrapperError::SerdeError(_)
+            | VintedWrapperError::CookiesError(_) => true,
+            VintedWrapperError::ItemError(code, _, _) => RetryPolicy::is_retryable(*code),
+            _ => false,
+        }
+    }
 }
 
 #[derive(Debug, Error)]
@@ -108,15 +158,14 @@ impl From<VintedWrapperError> for FangError {
     }
 }
 
-const DOMAINS: [&str; 22] = [
+const DOMAINS: [&str; 23] = [
     "fr", "es", "lu", "nl", "lt", "de", "at", "it", "co.uk", "pt", "com", "cz", "sk", "pl", "se",
-    "ro", "hu", "fi", "gr", "ie", "hr", "dk",
-    //"be",
+    "ro", "hu", "fi", "gr", "ie", "hr", "dk", "be",
 ];
 
 const DEFAULT_USER_AGENT: &str = "*/*";
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Host {
     Fr,
     Es,
@@ -140,71 +189,238 @@ pub enum Host {
     Ie,
     Hr,
     Dk,
-    //Be,
+    Be,
+}
+
+/// Every host, in the same order as `DOMAINS`
+const HOSTS: [Host; 23] = [
+    Host::Fr,
+    Host::Es,
+    Host::Lu,
+    Host::Nl,
+    Host::Lt,
+    Host::De,
+    Host::At,
+    Host::It,
+    Host::Uk,
+    Host::Pt,
+    Host::Com,
+    Host::Cz,
+    Host::Sk,
+    Host::Pl,
+    Host::Se,
+    Host::Ro,
+    Host::Hu,
+    Host::Fi,
+    Host::Gr,
+    Host::Ie,
+    Host::Hr,
+    Host::Dk,
+    Host::Be,
+];
+
+/// Market facts of a Host. Country ids come from the `COUNTRY` table, hosts missing from it have none
+struct HostInfo {
+    currency: Currency,
+    language: &'static str,
+    locale: &'static str,
+    country_id: Option<i32>,
 }
 
+impl HostInfo {
+    const fn new(
+        currency: Currency,
+        language: &'static str,
+        locale: &'static str,
+        country_id: Option<i32>,
+    ) -> Self {
+        HostInfo {
+            currency,
+            language,
+            locale,
+            country_id,
+        }
+    }
+}
+
+/// Error returned when a string is not a known Vinted host
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("`{0}` is not a valid Vinted host")]
+pub struct ParseHostError(String);
+
 impl Host {
     /// Returns true if a Host has the Euro as the currency
     pub fn is_euro_host(&self) -> bool {
-        matches!(
-            self,
-            Host::Es
-                | Host::It
-                | Host::Fr
-                | Host::Pt
-                | Host::Fi
-                | Host::Gr
-                | Host::Ie
-                | Host::Lu
-                | Host::Nl
-                | Host::At
-                | Host::De
-                | Host::Lt // | Host::Be
-        )
+        self.currency() == Currency::EUR
     }
 
     /// Returns a Host that has Euro as currency
     pub fn random_euro_host() -> Self {
-        let domains_euro: Vec<Host> = DOMAINS
-            .iter()
-            .map(|domain_str| (*domain_str).into())
-            .filter(|domain: &Host| domain.is_euro_host())
-            .collect();
+        let domains_euro: Vec<Host> = Host::iter().filter(Host::is_euro_host).collect();
 
         let random_index = rand::rng().random_range(0..domains_euro.len());
 
-        domains_euro[random_index].clone()
+        domains_euro[random_index]
+    }
+
+    /// Returns a random Host
+    pub fn random() -> Self {
+        HOSTS[rand::rng().random_range(0..HOSTS.len())]
+    }
+
+    /// Iterates over every Host
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vinted_rs::queries::Host;
+    ///
+    /// assert!(Host::iter().any(|host| host == Host::Uk));
+    /// ```
+    pub fn iter() -> impl Iterator<Item = Host> {
+        HOSTS.into_iter()
+    }
+
+    /// Returns the top level domain of the Host, e.g. `fr` or `co.uk`
+    pub fn domain(&self) -> &'static str {
+        (*self).into()
+    }
+
+    /// Returns the ISO 3166-1 alpha-2 code, in lowercase, of the country of the Host
+    pub fn iso_code(&self) -> &'static str {
+        match self {
+            Host::Uk => "gb",
+            Host::Com => "us",
+            host => host.domain(),
+        }
+    }
+
+    /// Returns the currency prices are shown in by the Host
+    pub fn currency(&self) -> Currency {
+        self.info().currency
+    }
+
+    /// Returns the ISO 639-1 code of the default language of the Host, e.g. `fr` or `de`
+    pub fn language(&self) -> &'static str {
+        self.info().language
+    }
+
+    /// Returns the locale used to format numbers and prices on the Host, e.g. `de-AT`
+    pub fn locale(&self) -> &'static str {
+        self.info().locale
+    }
+
+    /// Returns the Vinted id of the [`Country`](crate::model::filter::country::Country) of the Host, as used by
+    /// [`Filter::countries_ids`], if known
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vinted_rs::Filter;
+    /// use vinted_rs::queries::Host;
+    ///
+    /// let host = Host::Es;
+    /// let filter: Filter = Filter::builder().countries_ids(host.country_id()).build();
+    ///
+    /// assert_eq!(filter.countries_ids, Some(vec![7]));
+    /// ```
+    pub fn country_id(&self) -> Option<i32> {
+        self.info().country_id
+    }
+
+    fn info(&self) -> HostInfo {
+        match self {
+            Host::Fr => HostInfo::new(Currency::EUR, "fr", "fr-FR", Some(16)),
+            Host::Es => HostInfo::new(Currency::EUR, "es", "es-ES", Some(7)),
+            Host::Lu => HostInfo::new(Currency::EUR, "fr", "fr-LU", Some(20)),
+            Host::Nl => HostInfo::new(Currency::EUR, "nl", "nl-NL", Some(10)),
+            Host::Lt => HostInfo::new(Currency::EUR, "lt", "lt-LT", Some(1)),
+            Host::De => HostInfo::new(Currency::EUR, "de", "de-DE", Some(2)),
+            Host::At => HostInfo::new(Currency::EUR, "de", "de-AT", Some(4)),
+            Host::It => HostInfo::new(Currency::EUR, "it", "it-IT", Some(18)),
+            Host::Uk => HostInfo::new(Currency::GBP, "en", "en-GB", Some(13)),
+            Host::Pt => HostInfo::new(Currency::EUR, "pt", "pt-PT", Some(21)),
+            Host::Com => HostInfo::new(Currency::USD, "en", "en-US", Some(14)),
+            Host::Cz => HostInfo::new(Currency::CZK, "cs", "cs-CZ", Some(3)),
+            Host::Sk => HostInfo::new(Currency::EUR, "sk", "sk-SK", Some(22)),
+            Host::Pl => HostInfo::new(Currency::PLN, "pl", "pl-PL", Some(15)),
+            Host::Se => HostInfo::new(Currency::SEK, "sv", "sv-SE", Some(12)),
+            Host::Ro => HostInfo::new(Currency::RON, "ro", "ro-RO", Some(25)),
+            Host::Hu => HostInfo::new(Currency::HUF, "hu", "hu-HU", Some(24)),
+            Host::Fi => HostInfo::new(Currency::EUR, "fi", "fi-FI", None),
+            Host::Gr => HostInfo::new(Currency::EUR, "el", "el-GR", None),
+            Host::Ie => HostInfo::new(Currency::EUR, "en", "en-IE", None),
+            Host::Hr => HostInfo::new(Currency::EUR, "hr", "hr-HR", None),
+            Host::Dk => HostInfo::new(Currency::DKK, "da", "da-DK", None),
+            Host::Be => HostInfo::new(Currency::EUR, "fr", "fr-BE", Some(19)),
+        }
+    }
+
+    /// Returns the Host of a Vinted domain such as `www.vinted.fr` or `vinted.co.uk`, None if it is not one
+    pub fn from_domain(domain: &str) -> Option<Self> {
+        let domain = domain.trim().to_ascii_lowercase();
+        let domain = domain.strip_prefix("www.").unwrap_or(&domain);
+        let domain = domain.strip_prefix("vinted.")?;
+
+        Host::iter().find(|host| host.domain() == domain)
     }
 }
 
-impl From<&str> for Host {
-    fn from(string: &str) -> Self {
-        match string {
-            "fr" => Host::Fr,
-            "es" => Host::Es,
-            "lu" => Host::Lu,
-            "nl" => Host::Nl,
-            "lt" => Host::Lt,
-            "de" => Host::De,
-            "at" => Host::At,
-            "it" => Host::It,
-            "co.uk" => Host::Uk,
-            "pt" => Host::Pt,
-            "com" => Host::Com,
-            "cz" => Host::Cz,
-            "sk" => Host::Sk,
-            "pl" => Host::Pl,
-            "se" => Host::Se,
-            "ro" => Host::Ro,
-            "hu" => Host::Hu,
-            "fi" => Host::Fi,
-            "gr" => Host::Gr,
-            "ie" => Host::Ie,
-            "hr" => Host::Hr,
-            "dk" => Host::Dk,
-            //"be" => Host::Be,
-            _ => panic!("Not valid host"),
+impl FromStr for Host {
+    type Err = ParseHostError;
+
+    /// Parses, ignoring case, a Vinted domain (`www.vinted.co.uk`, `vinted.fr`), a top level domain (`co.uk`) or
+    /// the ISO code of the country of the host (`gb`, `uk`, `fr`)
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        if let Some(host) = Host::from_domain(string) {
+            return Ok(host);
         }
+
+        let code = string.trim().to_ascii_lowercase();
+
+        Host::iter()
+            .find(|host| {
+                host.domain() == code
+                    || host.iso_code() == code
+                    || (*host == Host::Uk && code == "uk")
+            })
+            .ok_or_else(|| ParseHostError(string.to_string()))
+    }
+}
+
+impl TryFrom<&str> for Host {
+    type Error = ParseHostError;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        string.parse()
+    }
+}
+
+impl TryFrom<String> for Host {
+    type Error = ParseHostError;
+
+    fn try_from(string: String) -> Result<Self, Self::Error> {
+        string.parse()
+    }
+}
+
+impl fmt::Display for Host {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.domain())
+    }
+}
+
+impl Serialize for Host {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.domain())
+    }
+}
+
+impl<'de> Deserialize<'de> for Host {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+
+        string.parse().map_err(serde::de::Error::custom)
     }
 }
 
@@ -233,7 +449,7 @@ impl From<Host> for &str {
             Host::Ie => DOMAINS[19],
             Host::Hr => DOMAINS[20],
             Host::Dk => DOMAINS[21],
-            //Host::Be => DOMAINS[22],
+            Host::Be => DOMAINS[22],
         }
     }
 }
@@ -249,64 +465,114 @@ impl From<Host> for &str {
 /// # Examples
 ///
 /// ```rust
+/// #![allow(deprecated)]
 /// use vinted_rs::queries::random_host;
 /// let host = random_host();
 /// println!("Random host: {}", host);
 /// ```
+#[deprecated(note = "use Host::random")]
 pub fn random_host<'a>() -> &'a str {
-    let random_index = rand::rng().random_range(0..DOMAINS.len());
-    DOMAINS[random_index]
+    Host::random().domain()
 }
 
 lazy_static! {
-    pub static ref COOKIE_STORE: Arc<CookieStoreMutex> = {
-        let cookie_store = CookieStore::new(None);
-        let cookie_store = CookieStoreMutex::new(cookie_store);
-        Arc::new(cookie_store)
-    };
+    /// Process-wide cookie jar.
+    ///
+    /// Wrappers own their cookie jar by default, pass this one to the builder's `cookie_store` to share cookies between them.
+    pub static ref COOKIE_STORE: Arc<CookieStoreMutex> = new_cookie_store();
+}
+
+/// Creates an empty cookie jar that can be given to one or more wrappers
+pub fn new_cookie_store() -> Arc<CookieStoreMutex> {
+    let cookie_store = CookieStore::new(None);
+    let cookie_store = CookieStoreMutex::new(cookie_store);
+    Arc::new(cookie_store)
 }
 
-async fn get_client(user_agent: Option<&str>, proxy: Option<Proxy>) -> Client {
+fn create_client(
+    user_agent: Option<&str>,
+    proxy: Option<Proxy>,
+    cookie_store: Arc<CookieStoreMutex>,
+) -> Client {
+    let mut builder = reqwest::ClientBuilder::new()
+        .user_agent(user_agent.unwrap_or(DEFAULT_USER_AGENT))
+        .cookie_provider(cookie_store);
+
     if let Some(proxy) = proxy {
-        create_client_proxy(user_agent, proxy)
-    } else {
-        create_client(user_agent)
+        builder = builder.proxy(proxy);
     }
+
+    builder.build().unwrap()
 }
 
-fn create_client_proxy(user_agent: Option<&str>, proxy: Proxy) -> Client {
-    reqwest::ClientBuilder::new()
-        .user_agent(user_agent.unwrap_or(DEFAULT_USER_AGENT))
-        .proxy(proxy)
-        .cookie_provider(COOKIE_STORE.clone())
-        .build()
-        .unwrap()
+/// Clients of the calls overriding the user agent, indexed by it. Once full, the least recently used one is dropped
+#[derive(Debug)]
+pub(crate) struct ClientCache {
+    capacity: usize,
+    clients: Vec<(String, Client)>,
 }
 
-fn create_client(user_agent: Option<&str>) -> Client {
-    reqwest::ClientBuilder::new()
-        .user_agent(user_agent.unwrap_or(DEFAULT_USER_AGENT))
-        .cookie_provider(COOKIE_STORE.clone())
-        .build()
-        .unwrap()
+impl ClientCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        ClientCache {
+            capacity,
+            clients: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the client kept for `user_agent`, creating it with `create` if there is none
+    pub(crate) fn get_or_insert_with(
+        &mut self,
+        user_agent: &str,
+        create: impl FnOnce() -> Client,
+    ) -> Client {
+        if let Some(position) = self.clients.iter().position(|(key, _)| key == user_agent) {
+            // Most recently used clients are kept last
+            let entry = self.clients.remove(position);
+            self.clients.push(entry);
+        } else {
+            let client = create();
+
+            if self.capacity == 0 {
+                return client;
+            }
+            if self.clients.len() == self.capacity {
+                self.clients.remove(0);
+            }
+            self.clients.push((user_agent.to_string(), client));
+        }
+
+        self.clients[self.clients.len() - 1].1.clone()
+    }
 }
+
 /// This will allow you to operate with multiple hosts using just one struct
 #[derive(Debug, Clone)]
-pub struct VintedWrappers<'a> {
-    wrappers: Vec<VintedWrapper<'a>>,
+pub struct VintedWrappers {
+    wrappers: Vec<VintedWrapper>,
     pub len: usize,
+    scheduler: Scheduler,
 }
 
-impl VintedWrappers<'_> {
+impl VintedWrappers {
     pub fn new_with_hosts(hosts: Vec<Host>) -> Self {
-        let len = hosts.len();
-
         let wrappers = hosts
             .into_iter()
             .map(VintedWrapper::new_with_host)
             .collect();
 
-        VintedWrappers { wrappers, len }
+        VintedWrappers::new_with_wrappers(wrappers)
+    }
+
+    /// Groups already configured wrappers, e.g. built with their own proxy or base URL
+    pub fn new_with_wrappers(wrappers: Vec<VintedWrapper>) -> Self {
+        let len = wrappers.len();
+
+        VintedWrappers {
+            wrappers,
+            len,
+            scheduler: Scheduler::default(),
+        }
     }
 
     pub fn all_wrappers() -> Self {
@@ -333,17 +599,24 @@ impl VintedWrappers<'_> {
             Host::Ie,
             Host::Hr,
             Host::Dk,
-            //Host::Be,
+            Host::Be,
         ];
         VintedWrappers::new_with_hosts(hosts)
     }
 
-    pub fn get_wrapper(&self, index: usize) -> &VintedWrapper<'_> {
+    /// Attaches `rate_limiter` to every wrapper of the set, so they share its budgets
+    pub fn set_rate_limiter(&mut self, rate_limiter: RateLimiter) {
+        for wrapper in &mut self.wrappers {
+            wrapper.set_rate_limiter(Some(rate_limiter.clone()));
+        }
+    }
+
+    pub fn get_wrapper(&self, index: usize) -> &VintedWrapper {
         &self.wrappers[index]
     }
 
     pub async fn lineal_fetch(
-        &mut self,
+        &self,
         filters: &Filter,
         num: u32,
         current: usize,
@@ -359,7 +632,7 @@ impl VintedWrappers<'_> {
     }
 
     pub async fn lineal_to_advance_items(
-        &mut self,
+        &self,
         item_id: i64,
         current: usize,
         user_agent: Option<&str>,
@@ -374,7 +647,7 @@ impl VintedWrappers<'_> {
     }
 }
 
-impl Default for VintedWrappers<'_> {
+impl Default for VintedWrappers {
     fn default() -> Self {
         let hosts = vec![Host::Es, Host::Fr, Host::Lu, Host::Pt, Host::It, Host::Nl];
         VintedWrappers::new_with_hosts(hosts)
@@ -385,21 +658,86 @@ impl Default for VintedWrappers<'_> {
 ///
 /// The `VintedWrapper` struct provides methods for retrieving items based on filters and handling cookies.
 ///
-#[derive(Debug, Clone)]
-pub struct VintedWrapper<'a> {
+/// Besides the `new*` constructors, a wrapper can be configured through its builder.
+///
+/// # Examples
+///
+/// ```rust
+/// use vinted_rs::VintedWrapper;
+/// use vinted_rs::queries::Host;
+///
+/// // Sends every request for `Host::Fr` to a local server instead of `https://www.vinted.fr`
+/// let wrapper = VintedWrapper::builder()
+///     .host(Host::Fr)
+///     .base_url(Host::Fr, "http://127.0.0.1:8080")
+///     .build();
+///
+/// // Wrappers sharing the process-wide cookie jar with their own user agent
+/// let wrapper = VintedWrapper::builder()
+///     .cookie_store(vinted_rs::queries::COOKIE_STORE.clone())
+///     .user_agent("Mozilla/5.0")
+///     .build();
+/// ```
+///
+/// Each wrapper keeps its own client, so connections are pooled across calls. The `user_agent` and `proxy` arguments of the
+/// query methods override the wrapper settings for a single call, sharing the wrapper's cookie jar. The client of each
+/// user agent and proxy is kept too, so calls rotating through a set of proxies also reuse their connections.
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct VintedWrapper {
+    #[builder(setter(skip), default = WRAPPER_ID.fetch_add(1, Ordering::SeqCst))]
     id: usize,
-    host: &'a str,
+    /// Host the wrapper sends its requests to. A random one is picked if not set
+    #[builder(default = Host::random())]
+    host: Host,
+    /// Base URLs overriding `https://www.vinted.{domain}`, indexed by host
+    #[builder(via_mutators, mutators(
+        /// Overrides the base URL (scheme, host and port) used when the wrapper targets `host`
+        ///
+        /// Useful to point the wrapper to a local stand-in server, e.g. `http://127.0.0.1:8080`
+        pub fn base_url(&mut self, host: Host, base_url: impl Into<String>) {
+            let base_url: String = base_url.into();
+
+            self.base_urls
+                .insert(host, base_url.trim_end_matches('/').to_string());
+        }
+    ))]
+    base_urls: HashMap<Host, String>,
+    /// Cookie jar of the wrapper. Every wrapper gets its own unless one is given, see [`COOKIE_STORE`] to share a single jar
+    #[builder(default = new_cookie_store())]
+    cookie_store: Arc<CookieStoreMutex>,
+    /// User agent sent by the wrapper's client
+    #[builder(default, setter(strip_option, into))]
+    user_agent: Option<String>,
+    /// Proxy used by the wrapper's client
+    #[builder(default, setter(strip_option))]
+    proxy: Option<Proxy>,
+    /// Client reused across calls so its connection pool is kept. When one is given, `user_agent` and `proxy` are
+    /// not applied to it, and it should use [`VintedWrapper::get_cookie_store`] as its cookie provider
+    #[builder(default = create_client(user_agent.as_deref(), proxy.clone(), cookie_store.clone()))]
+    client: Client,
+    /// Number of clients kept for calls overriding the user agent, 0 creates a new client on every such call
+    #[builder(default = 16)]
+    client_cache_size: usize,
+    /// Clients of the calls overriding the user agent. Shared by clones
+    #[builder(setter(skip), default = Arc::new(Mutex::new(ClientCache::new(client_cache_size))))]
+    override_clients: Arc<Mutex<ClientCache>>,
+    /// Retry policy applied to every request sent by the wrapper
+    #[builder(default)]
+    retry_policy: RetryPolicy,
+    /// Rate limiter every request waits for before being sent, it can be shared with other wrappers
+    #[builder(default, setter(strip_option))]
+    rate_limiter: Option<RateLimiter>,
 }
 
 static WRAPPER_ID: AtomicUsize = AtomicUsize::new(0);
 
-impl Default for VintedWrapper<'_> {
+impl Default for VintedWrapper {
     fn default() -> Self {
         Self::new_with_host(Host::Es)
     }
 }
 
-impl VintedWrapper<'_> {
+impl VintedWrapper {
     /// Creates a new `VintedWrapper` with a random host.
     ///
     /// The `new` function creates a new `VintedWrapper` instance with a random host domain. It initializes the cookie store and client for making requests to the Vinted API.
@@ -411,12 +749,7 @@ impl VintedWrapper<'_> {
     /// let wrapper = VintedWrapper::new();
     /// ```
     pub fn new() -> Self {
-        let id = WRAPPER_ID.fetch_add(1, Ordering::SeqCst);
-
-        VintedWrapper {
-            host: random_host(),
-            id,
-        }
+        VintedWrapper::builder().build()
     }
     /// Creates a new `VintedWrapper` with the specified host.
     ///
@@ -434,12 +767,7 @@ impl VintedWrapper<'_> {
     /// let wrapper = VintedWrapper::new_with_host(Host::Fr);
     /// ```
     pub fn new_with_host(host: Host) -> Self {
-        let id = WRAPPER_ID.fetch_add(1, Ordering::SeqCst);
-
-        VintedWrapper {
-            host: host.into(),
-            id,
-        }
+        VintedWrapper::builder().host(host).build()
     }
     /// Creates a new `VintedWrapper` with the specified currency.
     ///
@@ -459,13 +787,13 @@ impl VintedWrapper<'_> {
     pub fn new_with_currency(currency: Currency) -> Self {
         VintedWrapper::new_with_host(currency.into())
     }
-    /// Returns the current host domain.
+    /// Returns the current host.
     ///
-    /// The `get_host` method returns the current host domain used by the `VintedWrapper` instance.
+    /// The `get_host` method returns the current host used by the `VintedWrapper` instance.
     ///
     /// # Returns
     ///
-    /// A string reference containing the current host domain.
+    /// The current `Host`, displayed as its domain.
     ///
     /// # Examples
     ///
@@ -475,59 +803,342 @@ impl VintedWrapper<'_> {
     /// let host = wrapper.get_host();
     /// println!("Current host: {}", host);
     /// ```
-    pub fn get_host(&self) -> &str {
+    pub fn get_host(&self) -> Host {
         self.host
     }
 
     pub fn get_id(&self) -> &usize {
         &self.id
     }
+
+    /// Returns the base URL used for the current host.
+    ///
+    /// Defaults to `https://www.vinted.{host}` unless it was overridden with the builder's `base_url`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vinted_rs::VintedWrapper;
+    /// use vinted_rs::queries::Host;
+    ///
+    /// let wrapper = VintedWrapper::new_with_host(Host::Fr);
+    /// assert_eq!(wrapper.get_base_url(), "https://www.vinted.fr");
+    /// ```
+    pub fn get_base_url(&self) -> String {
+        match self.base_urls.get(&self.host) {
+            Some(base_url) => base_url.clone(),
+            None => format!("https://www.vinted.{}", self.host),
+        }
+    }
+
+    /// Returns the cookie jar used by the wrapper
+    pub fn get_cookie_store(&self) -> &Arc<CookieStoreMutex> {
+        &self.cookie_store
+    }
+
+    /// Returns the wrapper's client, or one sharing its cookie jar for the user agent and proxy the call overrides.
+    ///
+    /// Clients of a user agent override are kept, see the builder's `client_cache_size`. A proxy given to a call can
+    /// not be told apart from another one, so each of these calls gets a new client: set the proxy on the wrapper to
+    /// keep its connections.
+    fn get_client(&self, user_agent: Option<&str>, proxy: Option<Proxy>) -> Client {
+        if user_agent.is_none() && proxy.is_none() {
+            return self.client.clone();
+        }
+
+        let user_agent = user_agent.or(self.user_agent.as_deref());
+
+        if proxy.is_some() {
+            return create_client(user_agent, proxy, self.cookie_store.clone());
+        }
+
+        let proxy = self.proxy.clone();
+
+        self.override_clients
+            .lock()
+            .unwrap()
+            .get_or_insert_with(user_agent.unwrap_or(DEFAULT_USER_AGENT), || {
+                create_client(user_agent, proxy, self.cookie_store.clone())
+            })
+    }
+
+    /// Returns the number of clients kept for calls overriding the user agent
+    pub fn get_client_cache_size(&self) -> usize {
+        self.client_cache_size
+    }
+
+    /// Returns the retry policy applied to the requests of the wrapper
+    pub fn get_retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    pub fn set_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.retry_policy = retry_policy;
+    }
+
+    /// Returns the rate limiter attached to the wrapper, if any
+    pub fn get_rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_ref()
+    }
+
+    pub fn set_rate_limiter(&mut self, rate_limiter: Option<RateLimiter>) {
+        self.rate_limiter = rate_limiter;
+    }
+
+    /// Waits for the rate limiter, if any, to allow a new request to the current host
+    async fn wait_rate_limit(&self) {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire(self.host).await;
+        }
+    }
+
+    /// Sends the request built by `request` following the wrapper's [`RetryPolicy`] and returns the last response.
+    ///
+    /// If `cookies_client` is given, the host cookies are bootstrapped again with it before retrying a `401` or `403`
+    /// response. Requests that are not idempotent are only retried on `429` or a connection error.
+    async fn send<F>(
+        &self,
+        client: &Client,
+        request: F,
+        cookies_client: Option<&Client>,
+    ) -> Result<Response, reqwest::Error>
+    where
+        F: Fn(&Client) -> RequestBuilder,
+    {
+        let policy = &self.retry_policy;
+        let mut attempt = 1;
+
+        loop {
+            self.wait_rate_limit().await;
+
+            let built = request(client).build()?;
+            let idempotent = built.method().is_idempotent();
+
+            let response = match client.execute(built).await {
+                Ok(response) => response,
+                Err(err)
+                    if attempt < policy.max_attempts
+                        && (err.is_connect() || (idempotent && err.is_timeout())) =>
+                {
+                    let delay = policy.delay(attempt, None);
+                    debug!(
+                        "[{}] RETRY_{} in {:?} @ {} -> {}",
+                        self.id, attempt, delay, self.host, err
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                    continue;
+                }
+                Err(err) => return Err(err),
+            };
+
+            let status = response.status();
+            let retryable = if idempotent {
+                RetryPolicy::is_retryable(status)
+            } else {
+                RetryPolicy::is_retryable_non_idempotent(status)
+            };
+
+            if !retryable || attempt >= policy.max_attempts {
+                return Ok(response);
+            }
+
+            let retry_after = retry::retry_after(&response)
+                .and_then(|secs| u64::try_from(secs).ok())
+                .map(Duration::from_secs);
+
+            if policy.exceeds_max_delay(retry_after) {
+                debug!(
+                    "[{}] NO_RETRY, retry-after {:?} @ {} -> {}",
+                    self.id, retry_after, self.host, status
+                );
+                return Ok(response);
+            }
+
+            let delay = policy.delay(attempt, retry_after);
+
+            debug!(
+                "[{}] RETRY_{} in {:?} @ {} -> {}",
+                self.id, attempt, delay, self.host, status
+            );
+
+            tokio::time::sleep(delay).await;
+
+            if let Some(cookies_client) = cookies_client {
+                if policy.refresh_cookies && RetryPolicy::is_auth_error(status) {
+                    self.reset_cookies(cookies_client).await;
+                }
+            }
+
+            attempt += 1;
+        }
+    }
+
+    /// Drops the cookies of the current host and asks it for new ones, errors are left to the retried request
+    async fn reset_cookies(&self, client: &Client) {
+        debug!("[{}] RESET_COOKIES @ {}", self.id, self.host);
+
+        self.clear_host_cookies();
+
+        let request = format!("{}/", self.get_base_url());
+
+        self.wait_rate_limit().await;
+
+        if let Err(err) = client.get(&request).send().await {
+            debug!("[{}] RESET_COOKIES @ {} -> {}", self.id, self.host, err);
+        }
+    }
+
+    /// Gets cookies if needed, then requests `path` of the current host and deserializes the JSON answer.
+    ///
+    /// A non `200` answer is returned as an [`VintedWrapperError::ItemError`] described by `context`.
+    async fn get_json<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        context: &str,
+        user_agent: Option<&str>,
+        proxy_cookies: Option<Proxy>,
+        proxy_fetch: Option<Proxy>,
+    ) -> Result<T, VintedWrapperError> {
+        let client = self.get_client(user_agent, proxy_fetch);
+        let cookies_client = self.get_client(user_agent, proxy_cookies.clone());
+
+        if !self.has_valid_cookies() {
+            debug!(
+                "[{}] POST_GET_COOKIES -> Get {} @ {}",
+                self.id, context, self.host
+            );
+            self.get_cookies(user_agent, proxy_cookies).await?;
+        }
+
+        let url = format!("{}{}", self.get_base_url(), path);
+        debug!("[{}] GET_{} @ {}", self.id, context, self.host);
+        let json: Response = self
+            .send(&client, |client| client.get(&url), Some(&cookies_client))
+            .await?;
+
+        match json.status() {
+            StatusCode::OK => {
+                let raw_json = json.text().await?;
+                match serde_json::from_str::<T>(&raw_json) {
+                    Ok(value) => Ok(value),
+                    Err(serde_error) => {
+                        let error = SerdeJSONError::new(raw_json, serde_error);
+                        error!("Failed to deserialize: {}", error);
+                        Err(VintedWrapperError::SerdeError(error))
+                    }
+                }
+            }
+
+            code => {
+                let retry_after = retry::retry_after(&json);
+                Err(VintedWrapperError::ItemError(
+                    code,
+                    retry_after,
+                    format!("{}::{}", self.host, context),
+                ))
+            }
+        }
+    }
+
+    /// Returns the user agent used by a call, given its override
+    fn get_user_agent<'b>(&'b self, user_agent: Option<&'b str>) -> &'b str {
+        user_agent
+            .or(self.user_agent.as_deref())
+            .unwrap_or(DEFAULT_USER_AGENT)
+    }
+
+    /// Removes the cookies sent to the current host from the cookie jar, the cookies of other hosts sharing the jar
+    /// are kept
+    fn clear_host_cookies(&self) {
+        let Ok(url) = reqwest::Url::parse(&self.get_base_url()) else {
+            return;
+        };
+
+        let mut cookie_store = self.cookie_store.lock().unwrap();
+
+        let cookies: Vec<(String, String, String)> = cookie_store
+            .matches(&url)
+            .iter()
+            .filter_map(|cookie| {
+                Some((
+                    cookie.domain.as_cow()?.into_owned(),
+                    cookie.path.to_string(),
+                    cookie.name().to_string(),
+                ))
+            })
+            .collect();
+
+        for (domain, path, name) in cookies {
+            cookie_store.remove(&domain, &path, &name);
+        }
+    }
+
+    /// Returns true if the cookie store already holds a Cloudflare cookie valid for the current host
+    fn has_valid_cookies(&self) -> bool {
+        let Ok(url) = reqwest::Url::parse(&self.get_base_url()) else {
+            return false;
+        };
+
+        let cookie_store = self.cookie_store.lock().unwrap();
+
+        cookie_store
+            .matches(&url)
+            .iter()
+            .any(|cookie| cookie.name() == "__cf_bm")
+    }
     /// After changing host is always necessary to refresh cookies
     pub fn set_new_random_host(&mut self) {
-        self.host = random_host();
+        self.host = Host::random();
     }
 
     /// After changing host is always necessary to refresh cookies
     pub fn set_new_host(&mut self, host: Host) {
-        self.host = host.into();
+        self.host = host;
     }
 
     pub fn set_host_by_currency(&mut self, currency: Currency) {
         let host: Host = currency.into();
 
-        let actual_host: Host = self.host.into();
-
-        if !host.is_euro_host() || !actual_host.is_euro_host() {
-            let host_str: &str = host.into();
-
-            self.host = host_str;
+        if !host.is_euro_host() || !self.host.is_euro_host() {
+            self.host = host;
         }
     }
 
+    /// Gets the cookies of the current host from its home page.
+    ///
+    /// If the `GET` is still refused once the retry policy is exhausted, the page is requested again with a `POST`,
+    /// which some hosts answer with the cookies.
     pub async fn get_cookies(
         &self,
         user_agent: Option<&str>,
         proxy: Option<Proxy>,
     ) -> Result<(), CookieError> {
-        let client = get_client(user_agent, proxy).await;
+        let client = self.get_client(user_agent, proxy);
 
-        let request = format!("https://www.vinted.{}/", self.host);
+        let request = format!("{}/", self.get_base_url());
 
-        let mut response_cookies = client.get(&request).send().await?;
-        let max_retries = 3;
-        let mut i = 0;
+        let mut response_cookies = self
+            .send(&client, |client| client.get(&request), None)
+            .await?;
 
-        while response_cookies.status() != StatusCode::OK && i < max_retries {
-            response_cookies = client.post(&request).send().await?;
-            // tokio::time::sleep(Duration::from_millis(100)).await;
-            i += 1;
+        if response_cookies.status() != StatusCode::OK {
+            debug!(
+                "[{}] GET_COOKIES_POST @ {} -> {}",
+                self.id,
+                self.host,
+                response_cookies.status()
+            );
+            response_cookies = self
+                .send(&client, |client| client.post(&request), None)
+                .await?;
         }
 
         if response_cookies.status() != StatusCode::OK {
             return Err(CookieError::GetCookiesError((
                 response_cookies.status(),
-                String::from(self.get_host()),
-                user_agent.unwrap_or(DEFAULT_USER_AGENT).to_string(),
+                self.host.to_string(),
+                self.get_user_agent(user_agent).to_string(),
             )));
         }
 
@@ -571,38 +1182,25 @@ impl VintedWrapper<'_> {
         user_agent: Option<&str>,
         proxy: Option<Proxy>,
     ) -> Result<(), CookieError> {
-        let client = get_client(user_agent, proxy).await;
+        let client = self.get_client(user_agent, proxy);
 
-        let request = format!("https://www.vinted.{}/auth/token_refresh", self.host);
+        let request = format!("{}/auth/token_refresh", self.get_base_url());
 
-        let mut response_cookies = client.post(&request).send().await?;
-        let max_retries = 3;
-        let mut i = 0;
-
-        while response_cookies.status() != StatusCode::OK && i < max_retries {
-            response_cookies = client.post(&request).send().await?;
-            // tokio::time::sleep(Duration::from_millis(100)).await;
-            i += 1;
-        }
+        let response_cookies = self
+            .send(&client, |client| client.post(&request), None)
+            .await?;
 
         if response_cookies.status() != StatusCode::OK {
             return Err(CookieError::GetCookiesError((
                 response_cookies.status(),
-                String::from(self.get_host()),
-                user_agent.unwrap_or(DEFAULT_USER_AGENT).to_string(),
+                self.host.to_string(),
+                self.get_user_agent(user_agent).to_string(),
             )));
         }
 
         Ok(())
     }
 
-    fn substitute_if_first(first: &mut bool, url: &mut String) {
-        if *first {
-            url.replace_range(0..1, "?");
-            *first = false;
-        }
-    }
-
     /// Retrieves items from the Vinted API based on the provided filters.
     ///
     /// # Arguments
@@ -649,172 +1247,47 @@ impl VintedWrapper<'_> {
         user_agent: Option<&str>,
         proxy_cookies: Option<Proxy>,
         proxy_fetch: Option<Proxy>,
+    ) -> Result<Items, VintedWrapperError> {
+        self.get_items_page(filters, num, 1, user_agent, proxy_cookies, proxy_fetch)
+            .await
+    }
+
+    /// Retrieves a page of items from the Vinted API based on the provided filters.
+    ///
+    /// Works as [`get_items`](VintedWrapper::get_items), `num` being the number of items per page and `page` the page to
+    /// retrieve, starting at 1. See [`items_stream`](VintedWrapper::items_stream) to walk through every page.
+    pub async fn get_items_page(
+        &self,
+        filters: &Filter,
+        num: u32,
+        page: u32,
+        user_agent: Option<&str>,
+        proxy_cookies: Option<Proxy>,
+        proxy_fetch: Option<Proxy>,
     ) -> Result<Items, VintedWrapperError> {
         if num == 0 {
             return Err(VintedWrapperError::ItemNumberError);
         }
 
-        let client = get_client(user_agent, proxy_fetch).await;
-
-        let domain: &str = &format!("vinted.{}", self.host);
-
-        let cookie_not_valid;
-
-        {
-            let cookie_store_clone = COOKIE_STORE.lock().unwrap();
-            cookie_not_valid = cookie_store_clone.get(domain, "/", "__cf_bm").is_none();
-        }
-
-        if cookie_not_valid {
-            debug!(
-                "[{}] POST_GET_COOKIES -> Get {} items @ {}",
-                self.id, num, self.host
-            );
-            self.get_cookies(user_agent, proxy_cookies).await?;
-        }
+        filters.validate()?;
 
-        let mut first = true;
+        let mut query = form_urlencoded::Serializer::new(String::new());
 
-        let mut url = format!("https://www.vinted.{}/api/v2/catalog/items", self.host);
+        query
+            .extend_pairs(filters.query_pairs())
+            .append_pair("per_page", &num.to_string())
+            .append_pair("page", &page.max(1).to_string());
 
-        // Filter search text
-        if let Some(text) = &filters.search_text {
-            url = format!("{url}?search_text={text}");
-            first = false;
-        }
-
-        // Filter catalogo
-        if let Some(catalog_ids) = &filters.catalog_ids {
-            let mut catalog_args: String = format!("&catalog_ids={}", catalog_ids);
-
-            VintedWrapper::substitute_if_first(&mut first, &mut catalog_args);
-
-            url = format!("{url}{catalog_args}");
-        }
+        let path = format!("/api/v2/catalog/items?{}", query.finish());
 
-        // Filter colors
-        if let Some(color_ids) = &filters.color_ids {
-            let mut color_args: String = format!("&color_ids={}", color_ids);
-
-            VintedWrapper::substitute_if_first(&mut first, &mut color_args);
-
-            url = format!("{url}{color_args}");
-        }
-
-        //Filter brand
-        if let Some(brand_ids) = &filters.brand_ids {
-            let mut brand_args: String = format!("&brand_ids={}", brand_ids);
-
-            VintedWrapper::substitute_if_first(&mut first, &mut brand_args);
-
-            url = format!("{url}{brand_args}");
-        }
-
-        //Filter sizes
-        if let Some(size_ids) = &filters.size_ids {
-            let mut size_args: String = format!("&size_ids={}", size_ids);
-
-            VintedWrapper::substitute_if_first(&mut first, &mut size_args);
-
-            url = format!("{url}{size_args}");
-        }
-
-        //Filter materials
-        if let Some(material_ids) = &filters.material_ids {
-            let mut material_args: String = format!("&material_ids={}", material_ids);
-
-            VintedWrapper::substitute_if_first(&mut first, &mut material_args);
-
-            url = format!("{url}{material_args}");
-        }
-        //Filter country
-        if let Some(countries_ids) = &filters.countries_ids {
-            let mut countries_args: String = format!("&country_ids={}", countries_ids);
-
-            VintedWrapper::substitute_if_first(&mut first, &mut countries_args);
-
-            url = format!("{url}{countries_args}");
-        }
-
-        //Filter price from
-        if let Some(price_from) = &filters.price_from {
-            let mut price_from_arg: String = format!("&price_from={}", price_from);
-
-            VintedWrapper::substitute_if_first(&mut first, &mut price_from_arg);
-
-            url = format!("{url}{price_from_arg}");
-        }
-
-        //Filter price to
-        if let Some(price_to) = &filters.price_to {
-            let mut price_to_arg: String = format!("&price_to={}", price_to);
-
-            VintedWrapper::substitute_if_first(&mut first, &mut price_to_arg);
-
-            url = format!("{url}{price_to_arg}");
-        }
-
-        // Filter article_status
-        if let Some(vec) = &filters.article_status {
-            let querify_vec: Vec<&str> = vec.iter().map(|status| status.into()).collect();
-
-            let mut article_status_args: String = format!("&status_ids={}", querify_vec.join(","));
-
-            VintedWrapper::substitute_if_first(&mut first, &mut article_status_args);
-
-            url = format!("{url}{article_status_args}");
-        }
-
-        //Order by
-        if let Some(sort_by) = &filters.sort_by {
-            let sort_by_str: &str = sort_by.into();
-
-            let mut sort_by_arg = format!("&order={}", sort_by_str);
-
-            VintedWrapper::substitute_if_first(&mut first, &mut sort_by_arg);
-
-            url = format!("{url}{sort_by_arg}");
-        }
-
-        // Limitar el articulo a 1
-        let mut per_page_args = format!("&per_page={num}");
-
-        VintedWrapper::substitute_if_first(&mut first, &mut per_page_args);
-
-        url = format!("{url}{per_page_args}");
-
-        debug!("[{}] GET_{}_ITEMS @ {}", self.id, num, self.host);
-
-        let json: Response = client.get(url).send().await?;
-
-        match json.status() {
-            StatusCode::OK => {
-                // First, get the response body as text to enable debugging if deserialization fails
-                let raw_json = json.text().await?;
-
-                // Try to parse the JSON into Items
-                match serde_json::from_str::<Items>(&raw_json) {
-                    Ok(items) => Ok(items),
-                    Err(serde_error) => {
-                        let error = SerdeJSONError::new(raw_json, serde_error);
-                        error!("Failed to deserialize: {}", error); // Or use a logger
-                        Err(VintedWrapperError::SerdeError(error))
-                    }
-                }
-            }
-
-            code => {
-                let retry_after = json
-                    .headers()
-                    .get("retry-after")
-                    .map(|value| value.to_str().unwrap().to_string().parse().unwrap());
-                Err(VintedWrapperError::ItemError(
-                    code,
-                    retry_after,
-                    format!("{}::{}", self.host, json.url()),
-                ))
-            }
-        }
+        self.get_json(
+            &path,
+            &format!("CATALOG_ITEMS_PAGE_{page}"),
+            user_agent,
+            proxy_cookies,
+            proxy_fetch,
+        )
+        .await
     }
 
     /// Results additional information from an item based on its id
@@ -827,57 +1300,16 @@ impl VintedWrapper<'_> {
         proxy_cookies: Option<Proxy>,
         proxy_fetch: Option<Proxy>,
     ) -> Result<AdvancedItem, VintedWrapperError> {
-        let client = get_client(user_agent, proxy_fetch).await;
-
-        let domain: &str = &format!("vinted.{}", self.host);
-
-        let cookie_not_valid;
-
-        {
-            let cookie_store_clone = COOKIE_STORE.lock().unwrap();
-            cookie_not_valid = cookie_store_clone.get(domain, "/", "__cf_bm").is_none();
-        }
-
-        if cookie_not_valid {
-            debug!(
-                "[{}] POST_GET_COOKIES -> Get item {} @ {}",
-                self.id, item_id, self.host
-            );
-            self.get_cookies(user_agent, proxy_cookies).await?;
-        }
-
-        let url = format!("https://www.vinted.{}/api/v2/items/{}", self.host, item_id);
-        debug!(
-            "[{}] GET_ADVANCED_ITEM_{} @ {}",
-            self.id, item_id, self.host
-        );
-        let json: Response = client.get(url).send().await?;
-
-        match json.status() {
-            StatusCode::OK => {
-                let raw_json = json.text().await?;
-                match serde_json::from_str::<AdvancedItems>(&raw_json) {
-                    Ok(items) => Ok(items.item),
-                    Err(serde_error) => {
-                        // Log or debug the raw JSON content
-                        let error = SerdeJSONError::new(raw_json, serde_error);
-                        error!("Failed to deserialize: {}", error); // Or use a logger
-                        Err(VintedWrapperError::SerdeError(error))
-                    }
-                }
-            }
-
-            code => {
-                let retry_after = json
-                    .headers()
-                    .get("retry-after")
-                    .map(|value| value.to_str().unwrap().to_string().parse().unwrap());
-                Err(VintedWrapperError::ItemError(
-                    code,
-                    retry_after,
-                    format!("{}::{}", self.host, item_id),
-                ))
-            }
-        }
+        let items: AdvancedItems = self
+            .get_json(
+                &format!("/api/v2/items/{item_id}"),
+                &format!("ADVANCED_ITEM_{item_id}"),
+                user_agent,
+                proxy_cookies,
+                proxy_fetch,
+            )
+            .await?;
+
+        Ok(items.item)
     }
 }
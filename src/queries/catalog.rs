@@ -0,0 +1,521 @@
+use std::collections::HashMap;
+use std::future::Future;
+
+use log::debug;
+use reqwest::Proxy;
+use serde::Deserialize;
+use url::form_urlencoded;
+
+#[cfg(feature = "advanced_filters")]
+use bb8_postgres::tokio_postgres::{
+    Socket,
+    tls::{MakeTlsConnect, TlsConnect},
+};
+
+use super::{VintedWrapper, VintedWrapperError};
+#[cfg(feature = "advanced_filters")]
+use crate::db::DbController;
+use crate::model::filter::brand::Brand;
+use crate::model::filter::category::Category;
+use crate::model::filter::category_tree::CategoryTree;
+use crate::model::filter::colors::Color;
+use crate::model::filter::country::Country;
+use crate::model::filter::material::Material;
+use crate::model::filter::size::Size;
+
+/// Live catalog tree of a host, as returned by [`VintedWrapper::get_catalog`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Catalog {
+    /// Every category of the tree, parents before their children. Root categories have `parent_id` 0
+    pub categories: Vec<Category>,
+    /// Parent to child relations of the tree, with ids numbered from 1
+    pub tree: Vec<CategoryTree>,
+    /// Size group id to the id of the first category using it, see [`VintedWrapper::get_sizes`]
+    pub size_group_categories: HashMap<i32, i32>,
+}
+
+#[derive(Deserialize)]
+struct CatalogsResponse {
+    catalogs: Vec<ApiCatalog>,
+}
+
+#[derive(Deserialize)]
+struct ApiCatalog {
+    id: i32,
+    title: String,
+    #[serde(default)]
+    code: String,
+    #[serde(default)]
+    url: String,
+    #[serde(default)]
+    url_en: Option<String>,
+    #[serde(default)]
+    size_group_ids: Vec<i32>,
+    #[serde(default)]
+    catalogs: Vec<ApiCatalog>,
+}
+
+#[derive(Deserialize)]
+struct BrandsResponse {
+    brands: Vec<ApiBrand>,
+}
+
+#[derive(Deserialize)]
+struct ApiBrand {
+    id: i32,
+    title: String,
+    #[serde(default)]
+    url: String,
+}
+
+#[derive(Deserialize)]
+struct SizeGroupsResponse {
+    size_groups: Vec<ApiSizeGroup>,
+}
+
+#[derive(Deserialize)]
+struct ApiSizeGroup {
+    id: i32,
+    #[serde(alias = "description")]
+    caption: String,
+    sizes: Vec<ApiTitle>,
+}
+
+#[derive(Deserialize)]
+struct ApiTitle {
+    id: i32,
+    title: String,
+}
+
+#[derive(Deserialize)]
+struct ColorsResponse {
+    colors: Vec<ApiColor>,
+}
+
+#[derive(Deserialize)]
+struct ApiColor {
+    id: i32,
+    title: String,
+    hex: String,
+}
+
+#[derive(Deserialize)]
+struct MaterialsResponse {
+    materials: Vec<ApiTitle>,
+}
+
+#[derive(Deserialize)]
+struct CountriesResponse {
+    countries: Vec<ApiCountry>,
+}
+
+#[derive(Deserialize)]
+struct ApiCountry {
+    id: i32,
+    title: String,
+    iso_code: String,
+}
+
+impl Catalog {
+    fn push(&mut self, catalog: ApiCatalog, parent_id: i32) {
+        self.categories.push(
+            Category::builder()
+                .id(catalog.id)
+                .title(catalog.title)
+                .code(catalog.code)
+                .parent_id(parent_id)
+                .url_en(catalog.url_en.unwrap_or_else(|| catalog.url.clone()))
+                .url(catalog.url)
+                .build(),
+        );
+
+        if parent_id != 0 {
+            self.tree.push(
+                CategoryTree::builder()
+                    .id(self.tree.len() as i32 + 1)
+                    .parent_id(parent_id)
+                    .child_id(catalog.id)
+                    .build(),
+            );
+        }
+
+        for size_group_id in catalog.size_group_ids {
+            self.size_group_categories
+                .entry(size_group_id)
+                .or_insert(catalog.id);
+        }
+
+        for child in catalog.catalogs {
+            self.push(child, catalog.id);
+        }
+    }
+}
+
+/// Splits `title` into the Spanish, English and French fields of the model, the ones not matching `language` are
+/// left empty. Languages without a field of their own are stored as English.
+fn localized(language: &str, title: String) -> (String, String, String) {
+    match language {
+        "es" => (title, String::new(), String::new()),
+        "fr" => (String::new(), String::new(), title),
+        _ => (String::new(), title, String::new()),
+    }
+}
+
+/// Unicode flag of the country with the ISO 3166-1 alpha-2 code `iso_code`
+fn flag(iso_code: &str) -> String {
+    iso_code
+        .to_ascii_uppercase()
+        .chars()
+        .filter(char::is_ascii_uppercase)
+        .filter_map(|letter| char::from_u32(0x1F1E6 + (letter as u32 - 'A' as u32)))
+        .collect()
+}
+
+impl VintedWrapper {
+    /// Retrieves the current catalog tree of the host, with category titles in the host language
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vinted_rs::queries::{Host, VintedWrapper};
+    ///
+    /// async fn get_catalog_example() {
+    ///     let vinted = VintedWrapper::new_with_host(Host::Fr);
+    ///
+    ///     if let Ok(catalog) = vinted.get_catalog(None, None, None).await {
+    ///         for category in catalog.categories.iter().filter(|c| c.parent_id == 0) {
+    ///             println!("{} {}", category.id, category.title);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn get_catalog(
+        &self,
+        user_agent: Option<&str>,
+        proxy_cookies: Option<Proxy>,
+        proxy_fetch: Option<Proxy>,
+    ) -> Result<Catalog, VintedWrapperError> {
+        let response: CatalogsResponse = self
+            .get_json(
+                "/api/v2/catalogs",
+                "CATALOGS",
+                user_agent,
+                proxy_cookies,
+                proxy_fetch,
+            )
+            .await?;
+
+        let mut catalog = Catalog::default();
+
+        for root in response.catalogs {
+            catalog.push(root, 0);
+        }
+
+        Ok(catalog)
+    }
+
+    /// Retrieves the brands known by the host, only the ones matching `keyword` if given
+    pub async fn get_brands(
+        &self,
+        keyword: Option<&str>,
+        user_agent: Option<&str>,
+        proxy_cookies: Option<Proxy>,
+        proxy_fetch: Option<Proxy>,
+    ) -> Result<Vec<Brand>, VintedWrapperError> {
+        let path = match keyword {
+            Some(keyword) => format!(
+                "/api/v2/brands?{}",
+                form_urlencoded::Serializer::new(String::new())
+                    .append_pair("keyword", keyword)
+                    .finish()
+            ),
+            None => String::from("/api/v2/brands"),
+        };
+
+        let response: BrandsResponse = self
+            .get_json(&path, "BRANDS", user_agent, proxy_cookies, proxy_fetch)
+            .await?;
+
+        Ok(response
+            .brands
+            .into_iter()
+            .map(|brand| {
+                Brand::builder()
+                    .id(brand.id)
+                    .title(brand.title)
+                    .url(brand.url)
+                    .build()
+            })
+            .collect())
+    }
+
+    /// Retrieves the brands of the host whose title starts with `prefix`, ignoring case, in the order suggested by
+    /// Vinted
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vinted_rs::queries::{Host, VintedWrapper};
+    ///
+    /// async fn search_brands_example() {
+    ///     let vinted = VintedWrapper::new_with_host(Host::Fr);
+    ///
+    ///     if let Ok(brands) = vinted.search_brands("adi", None, None, None).await {
+    ///         for brand in brands {
+    ///             println!("{} {}", brand.id, brand.title);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn search_brands(
+        &self,
+        prefix: &str,
+        user_agent: Option<&str>,
+        proxy_cookies: Option<Proxy>,
+        proxy_fetch: Option<Proxy>,
+    ) -> Result<Vec<Brand>, VintedWrapperError> {
+        let prefix = prefix.trim().to_lowercase();
+
+        let mut brands = self
+            .get_brands(Some(&prefix), user_agent, proxy_cookies, proxy_fetch)
+            .await?;
+
+        brands.retain(|brand| brand.title.to_lowercase().starts_with(&prefix));
+
+        Ok(brands)
+    }
+
+    /// Same as [`search_brands`](VintedWrapper::search_brands), but gets the brands from `fallback`, given the trimmed
+    /// prefix, when the host can not be reached or fails the request.
+    ///
+    /// If the fallback fails too, the error of the host is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vinted_rs::model::filter::brand::Brand;
+    /// use vinted_rs::queries::{Host, VintedWrapper};
+    ///
+    /// async fn search_brands_or_else_example(cached: Vec<Brand>) {
+    ///     let vinted = VintedWrapper::new_with_host(Host::Fr);
+    ///
+    ///     let brands = vinted
+    ///         .search_brands_or_else("adi", |_| async { Ok::<_, ()>(cached) }, None, None, None)
+    ///         .await;
+    /// }
+    /// ```
+    pub async fn search_brands_or_else<F, Fut, E>(
+        &self,
+        prefix: &str,
+        fallback: F,
+        user_agent: Option<&str>,
+        proxy_cookies: Option<Proxy>,
+        proxy_fetch: Option<Proxy>,
+    ) -> Result<Vec<Brand>, VintedWrapperError>
+    where
+        F: FnOnce(String) -> Fut,
+        Fut: Future<Output = Result<Vec<Brand>, E>>,
+    {
+        match self
+            .search_brands(prefix, user_agent, proxy_cookies, proxy_fetch)
+            .await
+        {
+            Err(err) if err.is_host_failure() => {
+                debug!(
+                    "[{}] SEARCH_BRANDS_FALLBACK @ {} -> {}",
+                    self.id, self.host, err
+                );
+
+                fallback(prefix.trim().to_string()).await.map_err(|_| err)
+            }
+            result => result,
+        }
+    }
+
+    /// Same as [`search_brands`](VintedWrapper::search_brands), but searches the brands stored in `db` when the host
+    /// can not be reached or fails the request.
+    ///
+    /// If the database fails too, the error of the host is returned.
+    #[cfg(feature = "advanced_filters")]
+    pub async fn search_brands_or_db<Tls>(
+        &self,
+        prefix: &str,
+        db: &DbController<Tls>,
+        user_agent: Option<&str>,
+        proxy_cookies: Option<Proxy>,
+        proxy_fetch: Option<Proxy>,
+    ) -> Result<Vec<Brand>, VintedWrapperError>
+    where
+        Tls: MakeTlsConnect<Socket> + Clone + Send + Sync + 'static,
+        <Tls as MakeTlsConnect<Socket>>::Stream: Send + Sync,
+        <Tls as MakeTlsConnect<Socket>>::TlsConnect: Send,
+        <<Tls as MakeTlsConnect<Socket>>::TlsConnect as TlsConnect<Socket>>::Future: Send,
+    {
+        self.search_brands_or_else(
+            prefix,
+            |prefix| async move { db.get_brands_by_name(&prefix).await },
+            user_agent,
+            proxy_cookies,
+            proxy_fetch,
+        )
+        .await
+    }
+
+    /// Retrieves the sizes of every size group of the host.
+    ///
+    /// Titles only fill the Spanish, English or French field matching the host language, languages without a field of
+    /// their own are stored as English. The category of each size is taken from `catalog`, it is 0 for groups no
+    /// category uses.
+    pub async fn get_sizes(
+        &self,
+        catalog: &Catalog,
+        user_agent: Option<&str>,
+        proxy_cookies: Option<Proxy>,
+        proxy_fetch: Option<Proxy>,
+    ) -> Result<Vec<Size>, VintedWrapperError> {
+        let response: SizeGroupsResponse = self
+            .get_json(
+                "/api/v2/size_groups",
+                "SIZE_GROUPS",
+                user_agent,
+                proxy_cookies,
+                proxy_fetch,
+            )
+            .await?;
+
+        let language = self.host.language();
+        let mut sizes = vec![];
+
+        for group in response.size_groups {
+            let category_id = catalog
+                .size_group_categories
+                .get(&group.id)
+                .copied()
+                .unwrap_or_default();
+
+            for size in group.sizes {
+                let (title_es, title_en, title_fr) = localized(language, size.title);
+                let (size_type_es, size_type_en, size_type_fr) =
+                    localized(language, group.caption.clone());
+
+                sizes.push(
+                    Size::builder()
+                        .id(size.id)
+                        .title_es(title_es)
+                        .title_en(title_en)
+                        .title_fr(title_fr)
+                        .size_type_es(size_type_es)
+                        .size_type_en(size_type_en)
+                        .size_type_fr(size_type_fr)
+                        .category_id(category_id)
+                        .build(),
+                );
+            }
+        }
+
+        Ok(sizes)
+    }
+
+    /// Retrieves the colors of the host, with titles in the host language
+    pub async fn get_colors(
+        &self,
+        user_agent: Option<&str>,
+        proxy_cookies: Option<Proxy>,
+        proxy_fetch: Option<Proxy>,
+    ) -> Result<Vec<Color>, VintedWrapperError> {
+        let response: ColorsResponse = self
+            .get_json(
+                "/api/v2/colors",
+                "COLORS",
+                user_agent,
+                proxy_cookies,
+                proxy_fetch,
+            )
+            .await?;
+
+        Ok(response
+            .colors
+            .into_iter()
+            .map(|color| {
+                Color::builder()
+                    .id(color.id)
+                    .title(color.title)
+                    .hex(format!(
+                        "#{}",
+                        color.hex.trim_start_matches('#').to_ascii_uppercase()
+                    ))
+                    .build()
+            })
+            .collect())
+    }
+
+    /// Retrieves the materials of the host.
+    ///
+    /// Names only fill the Spanish, English or French field matching the host language, languages without a field of
+    /// their own are stored as English.
+    pub async fn get_materials(
+        &self,
+        user_agent: Option<&str>,
+        proxy_cookies: Option<Proxy>,
+        proxy_fetch: Option<Proxy>,
+    ) -> Result<Vec<Material>, VintedWrapperError> {
+        let response: MaterialsResponse = self
+            .get_json(
+                "/api/v2/materials",
+                "MATERIALS",
+                user_agent,
+                proxy_cookies,
+                proxy_fetch,
+            )
+            .await?;
+
+        let language = self.host.language();
+
+        Ok(response
+            .materials
+            .into_iter()
+            .map(|material| {
+                let (material_es, material_en, material_fr) = localized(language, material.title);
+
+                Material::builder()
+                    .id(material.id)
+                    .material_es(material_es)
+                    .material_en(material_en)
+                    .material_fr(material_fr)
+                    .build()
+            })
+            .collect())
+    }
+
+    /// Retrieves the countries members of the host can be located in, with names in the host language
+    pub async fn get_countries(
+        &self,
+        user_agent: Option<&str>,
+        proxy_cookies: Option<Proxy>,
+        proxy_fetch: Option<Proxy>,
+    ) -> Result<Vec<Country>, VintedWrapperError> {
+        let response: CountriesResponse = self
+            .get_json(
+                "/api/v2/countries",
+                "COUNTRIES",
+                user_agent,
+                proxy_cookies,
+                proxy_fetch,
+            )
+            .await?;
+
+        Ok(response
+            .countries
+            .into_iter()
+            .map(|country| {
+                Country::builder()
+                    .id(country.id)
+                    .flag(flag(&country.iso_code))
+                    .name(country.title.clone())
+                    .local_name(country.title)
+                    .iso_code(country.iso_code)
+                    .build()
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+
+use futures::StreamExt;
+use futures::stream;
+use log::debug;
+use reqwest::Proxy;
+use typed_builder::TypedBuilder;
+
+use super::{Host, VintedWrapperError, VintedWrappers};
+use crate::model::filter::Filter;
+use crate::model::item::Item;
+
+/// Options of [`VintedWrappers::fan_out_fetch`]
+///
+/// # Examples
+///
+/// ```rust
+/// use vinted_rs::queries::{FanOutOptions, Host};
+///
+/// let options = FanOutOptions::builder()
+///     .num(20)
+///     .hosts(vec![Host::Fr, Host::Es, Host::It])
+///     .concurrency(3)
+///     .build();
+/// ```
+#[derive(Debug, Clone, TypedBuilder, PartialEq, Eq)]
+pub struct FanOutOptions {
+    /// Number of items requested to each host
+    pub num: u32,
+    /// Hosts to query, every wrapper of the set if not given
+    #[builder(default, setter(strip_option, into))]
+    pub hosts: Option<Vec<Host>>,
+    /// Maximum number of requests in flight
+    #[builder(default = 4)]
+    pub concurrency: usize,
+}
+
+/// Item found by [`VintedWrappers::fan_out_fetch`], with the hosts it is listed on
+#[derive(Debug, Clone)]
+pub struct HostItem {
+    pub item: Item,
+    /// First host, in the order of the wrappers, that returned the item
+    pub host: Host,
+    /// Other hosts that returned the same listing
+    pub also_on: Vec<Host>,
+}
+
+/// Merged result of [`VintedWrappers::fan_out_fetch`]
+#[derive(Debug, Default)]
+pub struct FanOutItems {
+    /// Items of every host that answered, without duplicates, grouped by host in the order of the wrappers
+    pub items: Vec<HostItem>,
+    /// Hosts that failed, with their error. Requested hosts without a wrapper in the set are reported with a
+    /// [`MissingHostError`](VintedWrapperError::MissingHostError)
+    pub errors: Vec<(Host, VintedWrapperError)>,
+}
+
+impl FanOutItems {
+    /// Returns true if every requested host was queried and answered
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+impl VintedWrappers {
+    /// Runs `filters` against the wrappers of the set selected by `options`.
+    ///
+    /// A listing returned by several hosts is kept once, attributed to the first of them. A failing host does not fail
+    /// the search, its error is reported in [`FanOutItems::errors`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vinted_rs::Filter;
+    /// use vinted_rs::queries::{FanOutOptions, Host, VintedWrappers};
+    ///
+    /// async fn fan_out_example() {
+    ///     let wrappers = VintedWrappers::all_wrappers();
+    ///     let filter: Filter = Filter::builder().search_text(Some(String::from("nike"))).build();
+    ///
+    ///     let options = FanOutOptions::builder()
+    ///         .num(20)
+    ///         .hosts(vec![Host::Fr, Host::Es, Host::It])
+    ///         .concurrency(3)
+    ///         .build();
+    ///
+    ///     let results = wrappers.fan_out_fetch(&filter, &options, None, None, None).await;
+    ///
+    ///     for found in results.items {
+    ///         println!("{} @ {}", found.item.title, found.host);
+    ///     }
+    ///
+    ///     for (host, err) in results.errors {
+    ///         eprintln!("{host}: {err}");
+    ///     }
+    /// }
+    /// ```
+    pub async fn fan_out_fetch(
+        &self,
+        filters: &Filter,
+        options: &FanOutOptions,
+        user_agent: Option<&str>,
+        proxy_cookies: Option<Proxy>,
+        proxy_fetch: Option<Proxy>,
+    ) -> FanOutItems {
+        let hosts = options.hosts.as_deref();
+        let num = options.num;
+        let wrappers = self
+            .wrappers
+            .iter()
+            .filter(|wrapper| hosts.is_none_or(|hosts| hosts.contains(&wrapper.get_host())));
+
+        let mut results: Vec<_> = stream::iter(wrappers.enumerate())
+            .map(|(index, wrapper)| {
+                let proxy_cookies = proxy_cookies.clone();
+                let proxy_fetch = proxy_fetch.clone();
+
+                async move {
+                    let result = wrapper
+                        .get_items(filters, num, user_agent, proxy_cookies, proxy_fetch)
+                        .await;
+                    (index, wrapper.get_host(), result)
+                }
+            })
+            .buffer_unordered(options.concurrency.max(1))
+            .collect()
+            .await;
+
+        results.sort_by_key(|(index, _, _)| *index);
+
+        let mut merged = FanOutItems::default();
+        let mut positions: HashMap<i64, usize> = HashMap::new();
+
+        for (_, host, result) in results {
+            let items = match result {
+                Ok(items) => items.items,
+                Err(err) => {
+                    debug!("FAN_OUT_ERROR @ {} -> {}", host, err);
+                    merged.errors.push((host, err));
+                    continue;
+                }
+            };
+
+            for item in items {
+                match positions.get(&item.id) {
+                    Some(position) => {
+                        let found = &mut merged.items[*position];
+
+                        if found.host != host && !found.also_on.contains(&host) {
+                            found.also_on.push(host);
+                        }
+                    }
+                    None => {
+                        positions.insert(item.id, merged.items.len());
+                        merged.items.push(HostItem {
+                            item,
+                            host,
+                            also_on: vec![],
+                        });
+                    }
+                }
+            }
+        }
+
+        // Requested hosts without a wrapper are reported instead of being silently skipped
+        for host in hosts.unwrap_or_default() {
+            let has_wrapper = self
+                .wrappers
+                .iter()
+                .any(|wrapper| wrapper.get_host() == *host);
+            let reported = merged.errors.iter().any(|(reported, _)| reported == host);
+
+            if !has_wrapper && !reported {
+                debug!("FAN_OUT_MISSING @ {}", host);
+                merged
+                    .errors
+                    .push((*host, VintedWrapperError::MissingHostError(*host)));
+            }
+        }
+
+        merged
+    }
+}
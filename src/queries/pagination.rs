@@ -0,0 +1,120 @@
+use std::collections::{HashSet, VecDeque};
+
+use futures::Stream;
+use futures::stream;
+use log::debug;
+use reqwest::Proxy;
+
+use super::{VintedWrapper, VintedWrapperError};
+use crate::model::filter::Filter;
+use crate::model::item::Item;
+
+struct PageState {
+    next_page: u32,
+    pending: VecDeque<Item>,
+    seen: HashSet<i64>,
+    yielded: usize,
+    finished: bool,
+}
+
+impl VintedWrapper {
+    /// Returns a stream of the items matching `filters`, fetching `per_page` items per request.
+    ///
+    /// Pages are requested lazily as the stream is consumed, until `limit` items were yielded or the last page
+    /// given by the pagination is reached. Items that shift between pages while walking them are yielded only once.
+    ///
+    /// The stream ends after yielding an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use futures::StreamExt;
+    /// use vinted_rs::{Filter, VintedWrapper};
+    ///
+    /// async fn stream_example() {
+    ///     let wrapper = VintedWrapper::new();
+    ///     let filter: Filter = Filter::builder().search_text(Some(String::from("shoes"))).build();
+    ///
+    ///     let items = wrapper.items_stream(&filter, 96, Some(500), None, None, None);
+    ///     futures::pin_mut!(items);
+    ///
+    ///     while let Some(item) = items.next().await {
+    ///         println!("{}", item.unwrap().title);
+    ///     }
+    /// }
+    /// ```
+    pub fn items_stream<'b>(
+        &'b self,
+        filters: &'b Filter,
+        per_page: u32,
+        limit: Option<usize>,
+        user_agent: Option<&'b str>,
+        proxy_cookies: Option<Proxy>,
+        proxy_fetch: Option<Proxy>,
+    ) -> impl Stream<Item = Result<Item, VintedWrapperError>> + 'b {
+        let state = PageState {
+            next_page: 1,
+            pending: VecDeque::new(),
+            seen: HashSet::new(),
+            yielded: 0,
+            finished: false,
+        };
+
+        stream::unfold(state, move |mut state| {
+            let proxy_cookies = proxy_cookies.clone();
+            let proxy_fetch = proxy_fetch.clone();
+
+            async move {
+                loop {
+                    if limit.is_some_and(|limit| state.yielded >= limit) {
+                        return None;
+                    }
+
+                    if let Some(item) = state.pending.pop_front() {
+                        state.yielded += 1;
+                        return Some((Ok(item), state));
+                    }
+
+                    if state.finished {
+                        return None;
+                    }
+
+                    let page = state.next_page;
+
+                    match self
+                        .get_items_page(
+                            filters,
+                            per_page,
+                            page,
+                            user_agent,
+                            proxy_cookies.clone(),
+                            proxy_fetch.clone(),
+                        )
+                        .await
+                    {
+                        Ok(items) => {
+                            debug!(
+                                "[{}] PAGE {}/{} @ {}",
+                                self.id, page, items.pagination.total_pages, self.host
+                            );
+
+                            state.finished = items.items.is_empty()
+                                || i64::from(page) >= i64::from(items.pagination.total_pages);
+                            state.next_page += 1;
+
+                            for item in items.items {
+                                if state.seen.insert(item.id) {
+                                    state.pending.push_back(item);
+                                }
+                            }
+                        }
+                        Err(err) => {
+                            state.finished = true;
+                            return Some((Err(err), state));
+                        }
+                    }
+                }
+            }
+        })
+    }
+}
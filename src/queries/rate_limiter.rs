@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use super::Host;
+
+/// Budget of requests allowed by a [`RateLimiter`] bucket: `requests` every `period`, with bursts of up to `requests`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quota {
+    requests: u32,
+    period: Duration,
+}
+
+impl Quota {
+    /// Allows `requests` (at least 1) every `period`
+    pub fn new(requests: u32, period: Duration) -> Self {
+        Quota {
+            requests: requests.max(1),
+            period,
+        }
+    }
+
+    pub fn per_second(requests: u32) -> Self {
+        Quota::new(requests, Duration::from_secs(1))
+    }
+
+    pub fn per_minute(requests: u32) -> Self {
+        Quota::new(requests, Duration::from_secs(60))
+    }
+
+    /// Time needed to get back a single request
+    fn replenish_interval(&self) -> Duration {
+        self.period / self.requests
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    quota: Quota,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl Bucket {
+    fn new(quota: Quota) -> Self {
+        Bucket {
+            quota,
+            tokens: quota.requests as f64,
+            last_refill: Instant::now(),
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        let refilled = elapsed / self.quota.replenish_interval().as_secs_f64();
+
+        self.tokens = (self.tokens + refilled).min(self.quota.requests as f64);
+        self.last_refill = now;
+    }
+
+    /// Time to wait until a token is available, zero if there is one already
+    fn wait_time(&self) -> Duration {
+        if self.tokens >= 1.0 {
+            Duration::ZERO
+        } else {
+            self.quota.replenish_interval().mul_f64(1.0 - self.tokens)
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Buckets {
+    default_quota: Option<Quota>,
+    host_quotas: HashMap<Host, Quota>,
+    hosts: HashMap<Host, Bucket>,
+    global: Option<Bucket>,
+}
+
+/// Token bucket rate limiter shared by every wrapper it is attached to.
+///
+/// Each host has its own budget, given by [`with_host_quota`](RateLimiter::with_host_quota) or the default quota.
+/// An optional global budget bounds the requests sent to all hosts together.
+///
+/// Cloning a `RateLimiter` returns a handle to the same buckets.
+///
+/// # Examples
+///
+/// ```rust
+/// use vinted_rs::VintedWrapper;
+/// use vinted_rs::queries::{Host, Quota, RateLimiter, VintedWrappers};
+///
+/// let limiter = RateLimiter::new(Quota::per_second(2))
+///     .with_host_quota(Host::Fr, Quota::per_minute(30))
+///     .with_global_quota(Quota::per_second(5));
+///
+/// let wrapper = VintedWrapper::builder().rate_limiter(limiter.clone()).build();
+///
+/// let mut wrappers = VintedWrappers::default();
+/// wrappers.set_rate_limiter(limiter);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    buckets: Arc<Mutex<Buckets>>,
+}
+
+impl RateLimiter {
+    /// Creates a limiter giving `quota` to every host
+    pub fn new(quota: Quota) -> Self {
+        RateLimiter::with_default_quota(Some(quota))
+    }
+
+    /// Creates a limiter that only limits the hosts given a quota with [`with_host_quota`](RateLimiter::with_host_quota)
+    pub fn per_host() -> Self {
+        RateLimiter::with_default_quota(None)
+    }
+
+    fn with_default_quota(default_quota: Option<Quota>) -> Self {
+        RateLimiter {
+            buckets: Arc::new(Mutex::new(Buckets {
+                default_quota,
+                host_quotas: HashMap::new(),
+                hosts: HashMap::new(),
+                global: None,
+            })),
+        }
+    }
+
+    /// Gives `host` its own quota instead of the default one
+    pub fn with_host_quota(self, host: Host, quota: Quota) -> Self {
+        self.set_host_quota(host, quota);
+        self
+    }
+
+    /// Bounds the requests sent to all hosts together
+    pub fn with_global_quota(self, quota: Quota) -> Self {
+        self.buckets.lock().unwrap().global = Some(Bucket::new(quota));
+        self
+    }
+
+    /// Gives `host` its own quota, replacing its current budget
+    pub fn set_host_quota(&self, host: Host, quota: Quota) {
+        let mut buckets = self.buckets.lock().unwrap();
+
+        buckets.host_quotas.insert(host, quota);
+        buckets.hosts.remove(&host);
+    }
+
+    /// Waits until a request to `host` fits in its budget and the global one, then consumes it
+    pub async fn acquire(&self, host: Host) {
+        loop {
+            let wait = self.try_acquire(host);
+
+            if wait.is_zero() {
+                return;
+            }
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Consumes a request to `host` if available, otherwise returns the time to wait before trying again
+    fn try_acquire(&self, host: Host) -> Duration {
+        let now = Instant::now();
+        let mut guard = self.buckets.lock().unwrap();
+        let buckets = &mut *guard;
+
+        let quota = buckets
+            .host_quotas
+            .get(&host)
+            .copied()
+            .or(buckets.default_quota);
+
+        let mut bucket = quota.map(|quota| {
+            buckets
+                .hosts
+                .entry(host)
+                .or_insert_with(|| Bucket::new(quota))
+        });
+
+        if let Some(bucket) = bucket.as_mut() {
+            bucket.refill(now);
+        }
+        if let Some(bucket) = buckets.global.as_mut() {
+            bucket.refill(now);
+        }
+
+        let wait = bucket
+            .as_ref()
+            .map(|bucket| bucket.wait_time())
+            .unwrap_or_default()
+            .max(
+                buckets
+                    .global
+                    .as_ref()
+                    .map(|bucket| bucket.wait_time())
+                    .unwrap_or_default(),
+            );
+
+        if wait.is_zero() {
+            if let Some(bucket) = bucket {
+                bucket.tokens -= 1.0;
+            }
+            if let Some(bucket) = buckets.global.as_mut() {
+                bucket.tokens -= 1.0;
+            }
+        }
+
+        wait
+    }
+}
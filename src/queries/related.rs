@@ -0,0 +1,107 @@
+use reqwest::Proxy;
+use serde::Deserialize;
+
+use super::{VintedWrapper, VintedWrapperError, WardrobeOptions};
+use crate::model::item::Item;
+
+#[derive(Deserialize)]
+struct RelatedItems {
+    items: Vec<Item>,
+}
+
+impl VintedWrapper {
+    /// Retrieves up to `num` listings similar to the item `item_id`, as shown on the item page
+    ///
+    /// **Warning** This query result is affected by the host country, it has to be the same as the item is hosted at
+    pub async fn get_similar_items(
+        &self,
+        item_id: i64,
+        num: u32,
+        user_agent: Option<&str>,
+        proxy_cookies: Option<Proxy>,
+        proxy_fetch: Option<Proxy>,
+    ) -> Result<Vec<Item>, VintedWrapperError> {
+        if num == 0 {
+            return Err(VintedWrapperError::ItemNumberError);
+        }
+
+        let related: RelatedItems = self
+            .get_json(
+                &format!("/api/v2/items/{item_id}/similar_items?per_page={num}"),
+                &format!("SIMILAR_ITEMS_{item_id}"),
+                user_agent,
+                proxy_cookies,
+                proxy_fetch,
+            )
+            .await?;
+
+        let mut items = related.items;
+        items.retain(|item| item.id != item_id);
+        items.truncate(num as usize);
+
+        Ok(items)
+    }
+
+    /// Retrieves up to `num` other listings of the member selling the item `item_id`
+    ///
+    /// The owner is looked up with [`get_advanced_item`](VintedWrapper::get_advanced_item), then the first page of
+    /// their wardrobe is fetched.
+    ///
+    /// **Warning** This query result is affected by the host country, it has to be the same as the item is hosted at
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vinted_rs::queries::{Host, VintedWrapper};
+    ///
+    /// async fn more_from_seller_example() {
+    ///     let vinted = VintedWrapper::new_with_host(Host::Fr);
+    ///
+    ///     if let Ok(items) = vinted.get_more_from_seller(1234, 10, None, None, None).await {
+    ///         for item in items {
+    ///             println!("{} {}", item.title, item.price);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn get_more_from_seller(
+        &self,
+        item_id: i64,
+        num: u32,
+        user_agent: Option<&str>,
+        proxy_cookies: Option<Proxy>,
+        proxy_fetch: Option<Proxy>,
+    ) -> Result<Vec<Item>, VintedWrapperError> {
+        if num == 0 {
+            return Err(VintedWrapperError::ItemNumberError);
+        }
+
+        let item = self
+            .get_advanced_item(
+                item_id,
+                user_agent,
+                proxy_cookies.clone(),
+                proxy_fetch.clone(),
+            )
+            .await?;
+
+        // One more item is requested as the wardrobe includes the item itself
+        let wardrobe = self
+            .get_user_items(
+                item.user.id,
+                &WardrobeOptions::builder()
+                    .per_page(num.saturating_add(1))
+                    .build(),
+                user_agent,
+                proxy_cookies,
+                proxy_fetch,
+            )
+            .await?;
+
+        let mut items = wardrobe.items;
+        items.retain(|item| item.id != item_id);
+        items.truncate(num as usize);
+
+        Ok(items)
+    }
+}
@@ -0,0 +1,131 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use reqwest::Response;
+use reqwest::StatusCode;
+use typed_builder::TypedBuilder;
+
+/// Retry policy applied by a [`VintedWrapper`](crate::VintedWrapper) to every request it sends.
+///
+/// Requests answered with `429`, `401`, `403` or a server error are retried with an exponential backoff until
+/// `max_attempts` is reached, the last response is then handled as usual. Requests that are not idempotent, like
+/// `POST`, are only retried on `429` or when the connection could not be made, as the server may have handled them.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use vinted_rs::VintedWrapper;
+/// use vinted_rs::queries::RetryPolicy;
+///
+/// let policy = RetryPolicy::builder()
+///     .max_attempts(5)
+///     .base_delay(Duration::from_millis(500))
+///     .build();
+///
+/// let wrapper = VintedWrapper::builder().retry_policy(policy).build();
+/// ```
+#[derive(Debug, Clone, TypedBuilder, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts of a request, the first one included
+    #[builder(default = 3)]
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled on every following retry
+    #[builder(default = Duration::from_millis(250))]
+    pub base_delay: Duration,
+    /// Upper bound of the computed delay between two attempts
+    #[builder(default = Duration::from_secs(10))]
+    pub max_delay: Duration,
+    /// Randomizes the second half of the computed delay so concurrent wrappers do not retry at once
+    #[builder(default = true)]
+    pub jitter: bool,
+    /// Waits for the time given by the `retry-after` header, when present, instead of the computed delay.
+    ///
+    /// A `retry-after` longer than `max_delay` is not waited for: the request is not retried and the response is
+    /// returned, its `retry-after` is kept in [`VintedWrapperError::ItemError`](crate::VintedWrapperError::ItemError).
+    #[builder(default = true)]
+    pub respect_retry_after: bool,
+    /// Gets new cookies before retrying a request answered with `401` or `403`
+    #[builder(default = true)]
+    pub refresh_cookies: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::builder().build()
+    }
+}
+
+impl RetryPolicy {
+    /// Policy that never retries a request
+    pub fn none() -> Self {
+        RetryPolicy::builder().max_attempts(1).build()
+    }
+
+    /// Returns true if a response with this status is worth retrying
+    pub fn is_retryable(status: StatusCode) -> bool {
+        matches!(
+            status,
+            StatusCode::TOO_MANY_REQUESTS | StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN
+        ) || status.is_server_error()
+    }
+
+    /// Returns true if a response with this status is worth retrying for a request that is not idempotent
+    pub fn is_retryable_non_idempotent(status: StatusCode) -> bool {
+        status == StatusCode::TOO_MANY_REQUESTS
+    }
+
+    /// Returns true if a response with this status means the cookies of the wrapper are not valid anymore
+    pub fn is_auth_error(status: StatusCode) -> bool {
+        matches!(status, StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN)
+    }
+
+    /// Returns the time to wait after the failed `attempt` (starting at 1), given the `retry-after` of the response.
+    ///
+    /// The delay never exceeds `max_delay`.
+    pub fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let Some(retry_after) = retry_after.filter(|_| self.respect_retry_after) {
+            return retry_after.min(self.max_delay);
+        }
+
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        let delay = self.base_delay.saturating_mul(factor).min(self.max_delay);
+
+        if self.jitter {
+            let half = delay / 2;
+            half + half.mul_f64(rand::rng().random::<f64>())
+        } else {
+            delay
+        }
+    }
+
+    /// Returns true if the server asks to wait longer than `max_delay` before retrying
+    pub(crate) fn exceeds_max_delay(&self, retry_after: Option<Duration>) -> bool {
+        self.respect_retry_after
+            && retry_after.is_some_and(|retry_after| retry_after > self.max_delay)
+    }
+}
+
+/// Reads the `retry-after` header of a response as a number of seconds
+pub(crate) fn retry_after(response: &Response) -> Option<i32> {
+    response
+        .headers()
+        .get("retry-after")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| parse_retry_after(value, Utc::now()))
+}
+
+/// Parses a `retry-after` value, either a number of seconds or an HTTP date, as the seconds to wait from `now`
+pub(crate) fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<i32> {
+    let value = value.trim();
+
+    if let Ok(secs) = value.parse() {
+        return Some(secs);
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    let secs = (date.with_timezone(&Utc) - now).num_seconds().max(0);
+
+    Some(i32::try_from(secs).unwrap_or(i32::MAX))
+}
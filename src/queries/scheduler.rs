@@ -0,0 +1,252 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use log::debug;
+use rand::seq::SliceRandom;
+use reqwest::Proxy;
+use tokio::time::Instant;
+
+use super::{Host, VintedWrapperError, VintedWrappers};
+use crate::model::filter::Filter;
+use crate::model::items::Items;
+
+/// Order in which [`VintedWrappers::scheduled_fetch`] picks the hosts of the set
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SchedulingStrategy {
+    /// Each call starts with the host following the one the previous call started with
+    #[default]
+    RoundRobin,
+    /// Hosts never throttled first, then the ones throttled the longest time ago
+    LeastRecentlyThrottled,
+    /// Random host among the ones using the Euro, the other hosts are only used to fail over
+    RandomEuroHost,
+}
+
+/// Health of a host of a [`VintedWrappers`] set, as seen by its scheduler
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HostHealth {
+    pub host: Host,
+    /// Failures since the last successful request
+    pub consecutive_failures: u32,
+    /// Last time the host throttled or failed a request
+    pub last_throttled: Option<Instant>,
+    /// End of the cool-down of the host, None if it can be used
+    pub cooling_until: Option<Instant>,
+}
+
+impl HostHealth {
+    /// Returns true if the host is not cooling down
+    pub fn is_available(&self) -> bool {
+        self.cooling_until.is_none()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct WrapperHealth {
+    consecutive_failures: u32,
+    last_throttled: Option<Instant>,
+}
+
+#[derive(Debug, Default)]
+struct SchedulerState {
+    next: usize,
+    health: Vec<WrapperHealth>,
+}
+
+/// Picks the wrappers of a [`VintedWrappers`] set and tracks the health of their hosts.
+///
+/// Cloned sets share the same health.
+#[derive(Debug, Clone)]
+pub(crate) struct Scheduler {
+    strategy: SchedulingStrategy,
+    cool_down: Duration,
+    state: Arc<Mutex<SchedulerState>>,
+}
+
+impl Default for Scheduler {
+    fn default() -> Self {
+        Scheduler {
+            strategy: SchedulingStrategy::default(),
+            cool_down: Duration::from_secs(120),
+            state: Arc::new(Mutex::new(SchedulerState::default())),
+        }
+    }
+}
+
+impl Scheduler {
+    fn cooling_until(&self, health: &WrapperHealth, now: Instant) -> Option<Instant> {
+        health
+            .last_throttled
+            .map(|throttled| throttled + self.cool_down)
+            .filter(|until| *until > now)
+    }
+
+    /// Returns the indexes of the wrappers to try, in order, for the next request
+    fn candidates(&self, hosts: &[Host]) -> Vec<usize> {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        state.health.resize(hosts.len(), WrapperHealth::default());
+
+        let mut order: Vec<usize> = match self.strategy {
+            SchedulingStrategy::RoundRobin => {
+                let start = state.next % hosts.len().max(1);
+                state.next = start + 1;
+
+                (0..hosts.len())
+                    .map(|offset| (start + offset) % hosts.len())
+                    .collect()
+            }
+            SchedulingStrategy::LeastRecentlyThrottled => {
+                let mut order: Vec<usize> = (0..hosts.len()).collect();
+                order.sort_by_key(|index| state.health[*index].last_throttled);
+                order
+            }
+            SchedulingStrategy::RandomEuroHost => {
+                let (mut euro, mut others): (Vec<usize>, Vec<usize>) =
+                    (0..hosts.len()).partition(|index| hosts[*index].is_euro_host());
+
+                euro.shuffle(&mut rand::rng());
+                others.shuffle(&mut rand::rng());
+                euro.extend(others);
+                euro
+            }
+        };
+
+        let available: Vec<usize> = order
+            .iter()
+            .copied()
+            .filter(|index| self.cooling_until(&state.health[*index], now).is_none())
+            .collect();
+
+        if !available.is_empty() {
+            return available;
+        }
+
+        // Every host is cooling down, try first the ones whose cool-down ends sooner
+        order.sort_by_key(|index| state.health[*index].last_throttled);
+        order
+    }
+
+    fn record_success(&self, index: usize) {
+        if let Some(health) = self.state.lock().unwrap().health.get_mut(index) {
+            health.consecutive_failures = 0;
+        }
+    }
+
+    fn record_throttle(&self, index: usize) {
+        if let Some(health) = self.state.lock().unwrap().health.get_mut(index) {
+            health.consecutive_failures += 1;
+            health.last_throttled = Some(Instant::now());
+        }
+    }
+}
+
+impl VintedWrappers {
+    /// Sets the order in which [`scheduled_fetch`](VintedWrappers::scheduled_fetch) picks the hosts
+    pub fn set_strategy(&mut self, strategy: SchedulingStrategy) {
+        self.scheduler.strategy = strategy;
+    }
+
+    /// Sets for how long a host is skipped after throttling or failing a request
+    pub fn set_cool_down(&mut self, cool_down: Duration) {
+        self.scheduler.cool_down = cool_down;
+    }
+
+    /// Returns the health of the host of every wrapper of the set
+    pub fn health(&self) -> Vec<HostHealth> {
+        let now = Instant::now();
+        let state = self.scheduler.state.lock().unwrap();
+
+        self.wrappers
+            .iter()
+            .enumerate()
+            .map(|(index, wrapper)| {
+                let health = state.health.get(index).copied().unwrap_or_default();
+
+                HostHealth {
+                    host: wrapper.get_host(),
+                    consecutive_failures: health.consecutive_failures,
+                    last_throttled: health.last_throttled,
+                    cooling_until: self.scheduler.cooling_until(&health, now),
+                }
+            })
+            .collect()
+    }
+
+    /// Retrieves items from the host picked by the [`SchedulingStrategy`] of the set, returning them with the host
+    /// that answered.
+    ///
+    /// When a host throttles or fails the request, it cools down and the request fails over to the next available
+    /// host. Errors unrelated to the host, like an invalid filter, are returned straight away. If every host
+    /// fails, the last error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use vinted_rs::Filter;
+    /// use vinted_rs::queries::{SchedulingStrategy, VintedWrappers};
+    ///
+    /// async fn scheduled_fetch_example() {
+    ///     let mut wrappers = VintedWrappers::all_wrappers();
+    ///     wrappers.set_strategy(SchedulingStrategy::LeastRecentlyThrottled);
+    ///     wrappers.set_cool_down(Duration::from_secs(300));
+    ///
+    ///     let filter: Filter = Filter::builder().search_text(Some(String::from("nike"))).build();
+    ///
+    ///     match wrappers.scheduled_fetch(&filter, 10, None, None, None).await {
+    ///         Ok((host, items)) => println!("{} items from {host}", items.items.len()),
+    ///         Err(err) => eprintln!("{err}"),
+    ///     }
+    /// }
+    /// ```
+    pub async fn scheduled_fetch(
+        &self,
+        filters: &Filter,
+        num: u32,
+        user_agent: Option<&str>,
+        proxy_cookies: Option<Proxy>,
+        proxy_fetch: Option<Proxy>,
+    ) -> Result<(Host, Items), VintedWrapperError> {
+        let hosts: Vec<Host> = self
+            .wrappers
+            .iter()
+            .map(|wrapper| wrapper.get_host())
+            .collect();
+
+        let mut last_error = VintedWrapperError::EmptyWrappersError;
+
+        for index in self.scheduler.candidates(&hosts) {
+            let wrapper = &self.wrappers[index];
+
+            match wrapper
+                .get_items(
+                    filters,
+                    num,
+                    user_agent,
+                    proxy_cookies.clone(),
+                    proxy_fetch.clone(),
+                )
+                .await
+            {
+                Ok(items) => {
+                    self.scheduler.record_success(index);
+                    return Ok((hosts[index], items));
+                }
+                Err(err) if err.is_host_failure() => {
+                    debug!(
+                        "[{}] FAIL_OVER @ {} -> {}",
+                        wrapper.get_id(),
+                        hosts[index],
+                        err
+                    );
+                    self.scheduler.record_throttle(index);
+                    last_error = err;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        Err(last_error)
+    }
+}
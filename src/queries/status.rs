@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+
+use futures::StreamExt;
+use futures::stream;
+use log::debug;
+use reqwest::{Proxy, StatusCode};
+
+use super::{VintedWrapper, VintedWrapperError};
+use crate::model::item::ItemStatus;
+
+/// Change of status of an item between two checks of a [`StatusTracker`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatusChange {
+    pub item_id: i64,
+    /// Status at the previous check, None if the item was never checked
+    pub previous: Option<ItemStatus>,
+    pub current: ItemStatus,
+}
+
+/// Result of [`VintedWrapper::check_items_status`]
+#[derive(Debug, Default)]
+pub struct StatusReport {
+    /// Items whose status changed since the previous check, in the order of the checked ids
+    pub changes: Vec<StatusChange>,
+    /// Items that could not be checked, with their error. Their last known status is kept
+    pub errors: Vec<(i64, VintedWrapperError)>,
+}
+
+impl StatusReport {
+    /// Returns true if every item was checked
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Remembers the last known status of the items checked by [`VintedWrapper::check_items_status`]
+#[derive(Debug, Default, Clone)]
+pub struct StatusTracker {
+    statuses: HashMap<i64, ItemStatus>,
+}
+
+impl StatusTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Last known status of `item_id`
+    pub fn status(&self, item_id: i64) -> Option<ItemStatus> {
+        self.statuses.get(&item_id).copied()
+    }
+
+    /// Stops tracking `item_id`, returning its last known status
+    pub fn forget(&mut self, item_id: i64) -> Option<ItemStatus> {
+        self.statuses.remove(&item_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.statuses.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.statuses.is_empty()
+    }
+
+    /// Records `current` as the status of `item_id`, returns the change if it differs from the previous one
+    fn update(&mut self, item_id: i64, current: ItemStatus) -> Option<StatusChange> {
+        let previous = self.statuses.insert(item_id, current);
+
+        (previous != Some(current)).then_some(StatusChange {
+            item_id,
+            previous,
+            current,
+        })
+    }
+}
+
+impl VintedWrapper {
+    /// Returns whether the item `item_id` is active, reserved, hidden, closed or deleted
+    ///
+    /// **Warning** This query result is affected by the host country, it has to be the same as the item is hosted at
+    pub async fn check_item_status(
+        &self,
+        item_id: i64,
+        user_agent: Option<&str>,
+        proxy_cookies: Option<Proxy>,
+        proxy_fetch: Option<Proxy>,
+    ) -> Result<ItemStatus, VintedWrapperError> {
+        match self
+            .get_advanced_item(item_id, user_agent, proxy_cookies, proxy_fetch)
+            .await
+        {
+            Ok(item) => Ok(item.item_status()),
+            Err(VintedWrapperError::ItemError(StatusCode::NOT_FOUND, _, _)) => {
+                Ok(ItemStatus::Deleted)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Checks the status of every item of `item_ids`, with at most `concurrency` requests in flight, and reports the
+    /// ones that changed since they were last checked with `tracker`.
+    ///
+    /// Items checked for the first time are reported with no previous status. A failing item does not fail the
+    /// batch, its error is reported in [`StatusReport::errors`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vinted_rs::queries::{Host, StatusTracker, VintedWrapper};
+    ///
+    /// async fn check_items_status_example() {
+    ///     let vinted = VintedWrapper::new_with_host(Host::Fr);
+    ///     let mut tracker = StatusTracker::new();
+    ///
+    ///     let report = vinted
+    ///         .check_items_status(&mut tracker, &[1234, 5678], 4, None, None, None)
+    ///         .await;
+    ///
+    ///     for change in report.changes {
+    ///         println!("{}: {:?} -> {:?}", change.item_id, change.previous, change.current);
+    ///     }
+    /// }
+    /// ```
+    pub async fn check_items_status(
+        &self,
+        tracker: &mut StatusTracker,
+        item_ids: &[i64],
+        concurrency: usize,
+        user_agent: Option<&str>,
+        proxy_cookies: Option<Proxy>,
+        proxy_fetch: Option<Proxy>,
+    ) -> StatusReport {
+        let mut report = StatusReport::default();
+
+        // Get the cookies once, instead of once per concurrent request
+        if !item_ids.is_empty() && !self.has_valid_cookies() {
+            if let Err(err) = self.get_cookies(user_agent, proxy_cookies.clone()).await {
+                debug!(
+                    "[{}] CHECK_STATUS_COOKIES @ {} -> {}",
+                    self.id, self.host, err
+                );
+            }
+        }
+
+        let mut results: Vec<_> = stream::iter(item_ids.iter().copied().enumerate())
+            .map(|(index, item_id)| {
+                let proxy_cookies = proxy_cookies.clone();
+                let proxy_fetch = proxy_fetch.clone();
+
+                async move {
+                    let result = self
+                        .check_item_status(item_id, user_agent, proxy_cookies, proxy_fetch)
+                        .await;
+                    (index, item_id, result)
+                }
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await;
+
+        results.sort_by_key(|(index, _, _)| *index);
+
+        for (_, item_id, result) in results {
+            match result {
+                Ok(status) => report.changes.extend(tracker.update(item_id, status)),
+                Err(err) => {
+                    debug!(
+                        "[{}] CHECK_STATUS_{} @ {} -> {}",
+                        self.id, item_id, self.host, err
+                    );
+                    report.errors.push((item_id, err));
+                }
+            }
+        }
+
+        report
+    }
+}
@@ -0,0 +1,162 @@
+use reqwest::Proxy;
+use serde::Deserialize;
+use typed_builder::TypedBuilder;
+
+use super::{VintedWrapper, VintedWrapperError};
+use crate::model::feedback::UserFeedbacks;
+use crate::model::filter::SortBy;
+use crate::model::items::Items;
+use crate::model::user::UserProfile;
+
+#[derive(Deserialize)]
+struct UserProfileResponse {
+    user: UserProfile,
+}
+
+/// Page of a wardrobe retrieved by [`get_user_items`](VintedWrapper::get_user_items)
+///
+/// # Examples
+///
+/// ```rust
+/// use vinted_rs::model::filter::SortBy;
+/// use vinted_rs::queries::WardrobeOptions;
+///
+/// let options = WardrobeOptions::builder()
+///     .per_page(96)
+///     .page(2)
+///     .order(SortBy::PriceAscendant)
+///     .build();
+/// ```
+#[derive(Debug, Clone, TypedBuilder, PartialEq)]
+pub struct WardrobeOptions {
+    /// Number of items per page
+    #[builder(default = 20)]
+    pub per_page: u32,
+    /// Page to retrieve, starting at 1
+    #[builder(default = 1)]
+    pub page: u32,
+    /// Order of the items, newest first so new listings of a member can be monitored
+    #[builder(default = SortBy::NewestFirst)]
+    pub order: SortBy,
+}
+
+impl Default for WardrobeOptions {
+    fn default() -> Self {
+        WardrobeOptions::builder().build()
+    }
+}
+
+impl VintedWrapper {
+    /// Retrieves the public profile of a member: ratings, feedback counts, location and verifications
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vinted_rs::queries::{Host, VintedWrapper};
+    ///
+    /// async fn get_user_example() {
+    ///     let vinted = VintedWrapper::new_with_host(Host::Fr);
+    ///
+    ///     match vinted.get_user(1234, None, None, None).await {
+    ///         Ok(profile) => println!("{} has {} items", profile.login, profile.item_count),
+    ///         Err(err) => eprintln!("{err}"),
+    ///     }
+    /// }
+    /// ```
+    pub async fn get_user(
+        &self,
+        user_id: i64,
+        user_agent: Option<&str>,
+        proxy_cookies: Option<Proxy>,
+        proxy_fetch: Option<Proxy>,
+    ) -> Result<UserProfile, VintedWrapperError> {
+        let response: UserProfileResponse = self
+            .get_json(
+                &format!("/api/v2/users/{user_id}"),
+                &format!("USER_{user_id}"),
+                user_agent,
+                proxy_cookies,
+                proxy_fetch,
+            )
+            .await?;
+
+        Ok(response.user)
+    }
+
+    /// Retrieves a page of the wardrobe of a member, as described by `options`
+    ///
+    /// The returned [`Pagination`](crate::model::items::Pagination) tells how many pages the wardrobe has.
+    pub async fn get_user_items(
+        &self,
+        user_id: i64,
+        options: &WardrobeOptions,
+        user_agent: Option<&str>,
+        proxy_cookies: Option<Proxy>,
+        proxy_fetch: Option<Proxy>,
+    ) -> Result<Items, VintedWrapperError> {
+        if options.per_page == 0 {
+            return Err(VintedWrapperError::ItemNumberError);
+        }
+
+        self.get_json(
+            &format!(
+                "/api/v2/wardrobe/{user_id}/items?page={}&per_page={}&order={}",
+                options.page.max(1),
+                options.per_page,
+                <&str>::from(&options.order)
+            ),
+            &format!("USER_{user_id}_ITEMS_PAGE_{}", options.page),
+            user_agent,
+            proxy_cookies,
+            proxy_fetch,
+        )
+        .await
+    }
+
+    /// Retrieves the page `page` (starting at 1) of the feedback left to a member, with `per_page` entries per page
+    ///
+    /// [`UserFeedbacks::reputation`] summarizes the page, the reputation over every feedback is given by
+    /// [`Reputation::from`](crate::model::feedback::Reputation) on the [`get_user`](VintedWrapper::get_user) profile.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use vinted_rs::model::feedback::FeedbackKind;
+    /// use vinted_rs::queries::{Host, VintedWrapper};
+    ///
+    /// async fn get_user_feedback_example() {
+    ///     let vinted = VintedWrapper::new_with_host(Host::Fr);
+    ///
+    ///     if let Ok(page) = vinted.get_user_feedback(1234, 20, 1, None, None, None).await {
+    ///         for feedback in page.feedback.iter().filter(|f| f.kind() == FeedbackKind::Member) {
+    ///             println!("{}/5: {:?}", feedback.rating, feedback.comment);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn get_user_feedback(
+        &self,
+        user_id: i64,
+        per_page: u32,
+        page: u32,
+        user_agent: Option<&str>,
+        proxy_cookies: Option<Proxy>,
+        proxy_fetch: Option<Proxy>,
+    ) -> Result<UserFeedbacks, VintedWrapperError> {
+        if per_page == 0 {
+            return Err(VintedWrapperError::ItemNumberError);
+        }
+
+        self.get_json(
+            &format!(
+                "/api/v2/user_feedbacks?user_id={user_id}&page={}&per_page={per_page}&by=all",
+                page.max(1)
+            ),
+            &format!("USER_{user_id}_FEEDBACK_PAGE_{page}"),
+            user_agent,
+            proxy_cookies,
+            proxy_fetch,
+        )
+        .await
+    }
+}
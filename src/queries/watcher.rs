@@ -0,0 +1,211 @@
+use std::collections::{HashSet, VecDeque};
+use std::time::Duration;
+
+use futures::Stream;
+use futures::stream;
+use log::debug;
+use tokio::time::Instant;
+use typed_builder::TypedBuilder;
+
+use super::{RetryPolicy, VintedWrapper, VintedWrapperError};
+use crate::model::filter::{Filter, SortBy};
+use crate::model::item::Item;
+
+/// Keeps track of the item ids already seen by a [`Watcher`].
+///
+/// Implement it to persist seen ids between runs, e.g. in a database or a cache.
+pub trait SeenStore {
+    /// Marks `id` as seen, returns true if it was not seen before
+    fn insert(&mut self, id: i64) -> bool;
+
+    /// Returns true if `id` was already seen
+    fn contains(&self, id: i64) -> bool;
+}
+
+/// In memory [`SeenStore`], optionally bounded by a capacity after which the oldest ids are forgotten
+#[derive(Debug, Default, Clone)]
+pub struct MemorySeenStore {
+    ids: HashSet<i64>,
+    order: VecDeque<i64>,
+    capacity: Option<usize>,
+}
+
+impl MemorySeenStore {
+    /// Creates a store remembering the last `capacity` ids
+    pub fn with_capacity(capacity: usize) -> Self {
+        MemorySeenStore {
+            capacity: Some(capacity),
+            ..Default::default()
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+}
+
+impl SeenStore for MemorySeenStore {
+    fn insert(&mut self, id: i64) -> bool {
+        if !self.ids.insert(id) {
+            return false;
+        }
+
+        self.order.push_back(id);
+
+        if let Some(capacity) = self.capacity {
+            while self.order.len() > capacity {
+                if let Some(oldest) = self.order.pop_front() {
+                    self.ids.remove(&oldest);
+                }
+            }
+        }
+
+        true
+    }
+
+    fn contains(&self, id: i64) -> bool {
+        self.ids.contains(&id)
+    }
+}
+
+/// Polls a [`Filter`] sorted by [`SortBy::NewestFirst`] and yields only the items it did not see before.
+///
+/// Items found by the first poll are only marked as seen, unless `emit_initial` is set. Errors are yielded without
+/// ending the stream and, when they come from expired cookies, the cookies of the wrapper are dropped so the next
+/// poll gets new ones.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use futures::StreamExt;
+/// use vinted_rs::{Filter, VintedWrapper};
+/// use vinted_rs::queries::Watcher;
+///
+/// async fn watch_example() {
+///     let filter: Filter = Filter::builder().search_text(Some(String::from("nike"))).build();
+///
+///     let watcher = Watcher::builder()
+///         .wrapper(VintedWrapper::new())
+///         .filter(filter)
+///         .interval(Duration::from_secs(30))
+///         .build();
+///
+///     let new_items = watcher.into_stream();
+///     futures::pin_mut!(new_items);
+///
+///     while let Some(item) = new_items.next().await {
+///         match item {
+///             Ok(item) => println!("New item: {}", item.title),
+///             Err(err) => eprintln!("{err}"),
+///         }
+///     }
+/// }
+/// ```
+#[derive(TypedBuilder)]
+pub struct Watcher {
+    /// Wrapper used to poll the filter
+    wrapper: VintedWrapper,
+    /// Filter watched, its sort order is replaced by [`SortBy::NewestFirst`]
+    #[builder(setter(transform = |filter: Filter| Filter { sort_by: Some(SortBy::NewestFirst), ..filter }))]
+    filter: Filter,
+    /// Time between two polls
+    #[builder(default = Duration::from_secs(60))]
+    interval: Duration,
+    /// Number of items retrieved by each poll
+    #[builder(default = 96)]
+    per_page: u32,
+    /// Store of the item ids already seen
+    #[builder(
+        default = Box::new(MemorySeenStore::default()),
+        setter(transform = |store: impl SeenStore + Send + 'static| Box::new(store) as Box<dyn SeenStore + Send>)
+    )]
+    store: Box<dyn SeenStore + Send>,
+    /// Yields the items found by the first poll instead of only marking them as seen
+    #[builder(default = false)]
+    emit_initial: bool,
+    #[builder(setter(skip), default = true)]
+    first_poll: bool,
+}
+
+impl Watcher {
+    /// Polls the filter once and returns the unseen items, oldest first
+    pub async fn poll(&mut self) -> Result<Vec<Item>, VintedWrapperError> {
+        let items = match self
+            .wrapper
+            .get_items(&self.filter, self.per_page, None, None, None)
+            .await
+        {
+            Ok(items) => items,
+            Err(err) => {
+                if is_cookie_error(&err) {
+                    debug!(
+                        "[{}] WATCHER_DROP_COOKIES @ {}",
+                        self.wrapper.id, self.wrapper.host
+                    );
+                    self.wrapper.clear_host_cookies();
+                }
+                return Err(err);
+            }
+        };
+
+        let emit = !self.first_poll || self.emit_initial;
+        self.first_poll = false;
+
+        let new_items: Vec<Item> = items
+            .items
+            .into_iter()
+            .rev()
+            .filter(|item| self.store.insert(item.id))
+            .collect();
+
+        debug!(
+            "[{}] WATCHER_{}_NEW_ITEMS @ {}",
+            self.wrapper.id,
+            new_items.len(),
+            self.wrapper.host
+        );
+
+        Ok(if emit { new_items } else { vec![] })
+    }
+
+    /// Turns the watcher into an endless stream of new items, polling every `interval`
+    pub fn into_stream(self) -> impl Stream<Item = Result<Item, VintedWrapperError>> {
+        let state = (self, VecDeque::new(), None::<Instant>);
+
+        stream::unfold(
+            state,
+            |(mut watcher, mut pending, mut next_poll)| async move {
+                loop {
+                    if let Some(item) = pending.pop_front() {
+                        return Some((Ok(item), (watcher, pending, next_poll)));
+                    }
+
+                    if let Some(next_poll) = next_poll {
+                        tokio::time::sleep_until(next_poll).await;
+                    }
+
+                    next_poll = Some(Instant::now() + watcher.interval);
+
+                    match watcher.poll().await {
+                        Ok(items) => pending.extend(items),
+                        Err(err) => return Some((Err(err), (watcher, pending, next_poll))),
+                    }
+                }
+            },
+        )
+    }
+}
+
+/// Returns true if the error is caused by missing or expired cookies
+fn is_cookie_error(err: &VintedWrapperError) -> bool {
+    match err {
+        VintedWrapperError::CookiesError(_) => true,
+        VintedWrapperError::ItemError(code, _, _) => RetryPolicy::is_auth_error(*code),
+        _ => false,
+    }
+}
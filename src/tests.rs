@@ -19,6 +19,8 @@ lazy_static! {
 #[cfg(test)]
 pub mod db;
 #[cfg(test)]
+pub mod mock_server;
+#[cfg(test)]
 pub mod model;
 #[cfg(test)]
 pub mod queries;
@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+use crate::queries::{Host, RetryPolicy, VintedWrapper};
+
+/// Canned response served by the [`MockServer`]
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl MockResponse {
+    pub fn ok(body: impl Into<String>) -> Self {
+        MockResponse {
+            status: 200,
+            headers: vec![],
+            body: body.into(),
+        }
+    }
+
+    pub fn status(status: u16) -> Self {
+        MockResponse {
+            status,
+            headers: vec![],
+            body: String::new(),
+        }
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+/// Answer to the cookie bootstrap request, sets the Cloudflare cookie the wrapper looks for
+pub fn cookies_response() -> MockResponse {
+    MockResponse::ok("").header("Set-Cookie", "__cf_bm=mock; Path=/")
+}
+
+/// Local stand-in for Vinted, serves canned responses by path and records every request target
+///
+/// Responses for a path are served in order, the last one is repeated once the others are consumed
+pub struct MockServer {
+    addr: SocketAddr,
+    requests: Arc<Mutex<Vec<(String, String)>>>,
+}
+
+impl MockServer {
+    pub async fn start(routes: Vec<(&str, Vec<MockResponse>)>) -> MockServer {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests = Arc::new(Mutex::new(vec![]));
+        let routes: Arc<Mutex<HashMap<String, Vec<MockResponse>>>> = Arc::new(Mutex::new(
+            routes
+                .into_iter()
+                .map(|(path, responses)| (path.to_string(), responses))
+                .collect(),
+        ));
+
+        let requests_clone = requests.clone();
+
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(handle(stream, routes.clone(), requests_clone.clone()));
+            }
+        });
+
+        MockServer { addr, requests }
+    }
+
+    /// Starts a server answering the cookie bootstrap request on `/` besides `routes`
+    pub async fn with_cookies(mut routes: Vec<(&str, Vec<MockResponse>)>) -> MockServer {
+        routes.insert(0, ("/", vec![cookies_response()]));
+        MockServer::start(routes).await
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Request targets (path and query) received so far
+    pub fn requests(&self) -> Vec<String> {
+        self.requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(_, target)| target.clone())
+            .collect()
+    }
+
+    /// Methods of the received requests whose path is `path`, in order
+    pub fn methods(&self, path: &str) -> Vec<String> {
+        self.requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, target)| target.split('?').next() == Some(path))
+            .map(|(method, _)| method.clone())
+            .collect()
+    }
+
+    /// Number of received requests whose path is `path`
+    pub fn hits(&self, path: &str) -> usize {
+        self.requests()
+            .iter()
+            .filter(|target| target.split('?').next() == Some(path))
+            .count()
+    }
+
+    /// Returns true if `target` (path and query) was requested
+    pub fn requested(&self, target: &str) -> bool {
+        self.requests().iter().any(|request| request == target)
+    }
+}
+
+/// Wrapper targeting `host`, with its base URL pointing to `server`
+pub fn mock_wrapper(host: Host, server: &MockServer) -> VintedWrapper {
+    VintedWrapper::builder()
+        .host(host)
+        .base_url(host, server.url())
+        .build()
+}
+
+/// Same as [`mock_wrapper`], failing requests are not retried
+pub fn mock_wrapper_without_retry(host: Host, server: &MockServer) -> VintedWrapper {
+    VintedWrapper::builder()
+        .host(host)
+        .base_url(host, server.url())
+        .retry_policy(RetryPolicy::none())
+        .build()
+}
+
+async fn handle(
+    mut stream: TcpStream,
+    routes: Arc<Mutex<HashMap<String, Vec<MockResponse>>>>,
+    requests: Arc<Mutex<Vec<(String, String)>>>,
+) {
+    let mut raw = vec![];
+    let mut buf = [0u8; 1024];
+
+    while !raw.windows(4).any(|window| window == b"\r\n\r\n") {
+        match stream.read(&mut buf).await {
+            Ok(0) | Err(_) => return,
+            Ok(n) => raw.extend_from_slice(&buf[..n]),
+        }
+    }
+
+    let head = String::from_utf8_lossy(&raw);
+    let mut request_line = head.split_whitespace();
+    let method = request_line.next().unwrap_or("GET").to_string();
+    let target = request_line.next().unwrap_or("/").to_string();
+    let path = target.split('?').next().unwrap_or("/").to_string();
+
+    requests.lock().unwrap().push((method, target));
+
+    let response = {
+        let mut routes = routes.lock().unwrap();
+        match routes.get_mut(&path) {
+            Some(responses) if responses.len() > 1 => responses.remove(0),
+            Some(responses) if !responses.is_empty() => responses[0].clone(),
+            _ => MockResponse::status(404),
+        }
+    };
+
+    let mut reply = format!(
+        "HTTP/1.1 {} MOCK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n",
+        response.status,
+        response.body.len()
+    );
+
+    for (name, value) in &response.headers {
+        reply.push_str(&format!("{name}: {value}\r\n"));
+    }
+
+    reply.push_str("\r\n");
+    reply.push_str(&response.body);
+
+    let _ = stream.write_all(reply.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
+
+/// Minimal catalog item as returned by `/api/v2/catalog/items`
+pub fn item_json(id: i64) -> String {
+    format!(
+        r#"{{"id":{id},"title":"Item {id}","size_title":"M","brand_title":"Nike","price":{{"amount":"10.0","currency_code":"EUR"}},"photo":null,"url":"https://www.vinted.fr/items/{id}","is_visible":true,"promoted":false,"favourite_count":0}}"#
+    )
+}
+
+/// Catalog page containing `ids`
+pub fn items_json(ids: &[i64], current_page: i32, total_pages: i32) -> String {
+    let items: Vec<String> = ids.iter().map(|id| item_json(*id)).collect();
+
+    format!(
+        r#"{{"items":[{}],"pagination":{{"current_page":{current_page},"total_pages":{total_pages},"total_entries":{},"per_page":{},"time":1700000000}}}}"#,
+        items.join(","),
+        ids.len() as i32 * total_pages,
+        ids.len()
+    )
+}
+
+/// Minimal item as returned by `/api/v2/items/{id}`, inside its `item` envelope
+pub fn advanced_item_json(id: i64) -> String {
+    format!(
+        r#"{{"item":{{"id":{id},"title":"Item {id}","description":"","size":"M","brand":"Nike","package_size_id":1,"country_id":16,"active_bid_count":0,"favourite_count":0,"view_count":0,"moderation_status":10,"related_catalog_ids":[],"original_price_numeric":"10.0","currency":"EUR","price_numeric":"10.0","service_fee":"1.20","created_at_ts":"2024-05-10T18:30:00+02:00","last_push_up_at":"2024-05-12T08:00:00+02:00","updated_at_ts":1715500000,"photos":[],"url":"https://www.vinted.fr/items/{id}","user":{{"id":1,"login":"seller","expose_location":false,"country_id":16,"accepted_pay_in_methods":[]}}}}}}"#
+    )
+}
+
+/// Member profile as returned by `/api/v2/users/{id}`, inside its `user` envelope
+pub fn user_json(id: i64) -> String {
+    format!(
+        r#"{{"user":{{"id":{id},"login":"seller{id}","photo":null,"feedback_reputation":0.9,"feedback_count":20,"positive_feedback_count":18,"neutral_feedback_count":1,"negative_feedback_count":1,"item_count":3,"followers_count":5,"following_count":2,"country_id":16,"country_title":"France","city":"Paris","is_online":false,"business":false,"last_loged_on_ts":"2024-05-10T18:30:00+02:00","verification":{{"email":{{"valid":true,"available":true}},"facebook":{{"valid":false,"available":true}},"google":{{"valid":false,"available":true}},"phone":{{"valid":false,"available":false}}}}}}}}"#
+    )
+}
+
+/// Feedback page as returned by `/api/v2/user_feedbacks`, one review of each `ratings`, the last one left by Vinted
+pub fn user_feedbacks_json(ratings: &[i32]) -> String {
+    let feedback: Vec<String> = ratings
+        .iter()
+        .enumerate()
+        .map(|(index, rating)| {
+            if index + 1 == ratings.len() {
+                format!(
+                    r#"{{"id":{index},"rating":{rating},"feedback":"Automatic feedback","user":null,"system_feedback":true,"created_at_ts":1715500000}}"#
+                )
+            } else {
+                format!(
+                    r#"{{"id":{index},"rating":{rating},"feedback":"Review {index}","user":{{"id":{index},"login":"buyer{index}","photo":null}},"item_id":{index},"item_title":"Item {index}","system_feedback":false,"created_at_ts":"2024-05-10T18:30:00+02:00"}}"#
+                )
+            }
+        })
+        .collect();
+
+    format!(
+        r#"{{"user_feedbacks":[{}],"pagination":{{"current_page":1,"total_pages":1,"total_entries":{},"per_page":20,"time":1700000000}}}}"#,
+        feedback.join(","),
+        ratings.len()
+    )
+}
@@ -1 +1,384 @@
+use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+use rust_decimal::Decimal;
+use url::Url;
 
+use crate::model::exchange::{ExchangeError, ExchangeRates, FileRates, StaticRates, sort_by_price};
+use crate::model::filter::brand::Brand;
+use crate::model::filter::{
+    ArticleStatus, Currency, Filter, FilterProblem, FilterUrlError, SortBy, UnknownArticleStatus,
+};
+use crate::model::item::{Item, ParsePriceError, Price};
+use crate::model::items::{AdvancedItems, Items, Pagination};
+use crate::queries::Host;
+use crate::tests::mock_server::{advanced_item_json, item_json, items_json};
+
+#[test]
+fn test_filter_query_string() {
+    let filter: Filter = Filter::builder()
+        .search_text(Some(String::from("Zapatillas & botas #1")))
+        .brand_ids([14, 53])
+        .price_from(Some(15.0))
+        .price_to(Some(20.5))
+        .article_status(Some(vec![ArticleStatus::NewTags, ArticleStatus::Good]))
+        .sort_by(Some(SortBy::NewestFirst))
+        .build();
+
+    assert_eq!(
+        filter.to_query_string(),
+        "search_text=Zapatillas+%26+botas+%231&brand_ids=14%2C53&price_from=15&price_to=20.5&status_ids=6%2C3&order=newest_first"
+    );
+
+    let filter: Filter = Filter::builder()
+        .search_text(Some(String::from("Ünterhemd")))
+        .build();
+
+    assert_eq!(filter.to_query_string(), "search_text=%C3%9Cnterhemd");
+    assert_eq!(Filter::builder().build().to_query_string(), "");
+}
+
+#[test]
+fn test_filter_from_url() {
+    let (filter, host) = Filter::from_url(
+        "https://www.vinted.co.uk/catalog?search_text=nike%20air&brand_ids[]=53&brand_ids[]=14&catalog[]=4\
+         &status_ids[]=6&status_ids[]=1&price_from=5&price_to=20.5&order=price_low_to_high&currency=GBP&page=2",
+    )
+    .unwrap();
+
+    assert!(matches!(host, Host::Uk));
+    assert_eq!(filter.search_text, Some(String::from("nike air")));
+    assert_eq!(filter.brand_ids, Some(vec![53, 14]));
+    assert_eq!(filter.catalog_ids, Some(vec![4]));
+    assert_eq!(
+        filter.article_status,
+        Some(vec![ArticleStatus::NewTags, ArticleStatus::NewNoTags])
+    );
+    assert_eq!(filter.price_from, Some(5.0));
+    assert_eq!(filter.price_to, Some(20.5));
+    assert_eq!(filter.sort_by, Some(SortBy::PriceAscendant));
+    assert_eq!(filter.color_ids, None);
+
+    let filter =
+        Filter::try_from(Url::parse("http://localhost/catalog?size_ids=102,1226").unwrap())
+            .unwrap();
+    assert_eq!(filter.size_ids, Some(vec![102, 1226]));
+
+    let (filter, _) = Filter::from_url(&format!(
+        "https://www.vinted.fr/catalog?{}",
+        filter.to_query_string()
+    ))
+    .unwrap();
+    assert_eq!(filter.size_ids, Some(vec![102, 1226]));
+
+    assert!(matches!(
+        Filter::from_url("https://www.example.com/catalog?search_text=nike"),
+        Err(FilterUrlError::HostError(_))
+    ));
+    assert!(matches!(
+        Filter::from_url("https://www.vinted.fr/catalog?brand_ids[]=nike"),
+        Err(FilterUrlError::ParameterError(..))
+    ));
+    assert!(matches!(
+        Filter::from_url("https://www.vinted.fr/catalog?order=cheapest"),
+        Err(FilterUrlError::ParameterError(..))
+    ));
+    assert!(matches!(
+        Filter::from_url("not a url"),
+        Err(FilterUrlError::UrlError(_))
+    ));
+}
+
+#[test]
+fn test_filter_typed_ids() {
+    let nike = Brand::builder()
+        .id(53)
+        .title(String::from("Nike"))
+        .url(String::from("https://www.vinted.fr/brand/53-nike"))
+        .build();
+    let brands = vec![nike];
+
+    let filter: Filter = Filter::builder()
+        .brand_ids(&brands)
+        .catalog_ids([4, 16])
+        .build();
+
+    assert_eq!(filter.brand_ids, Some(vec![53]));
+    assert_eq!(filter.catalog_ids, Some(vec![4, 16]));
+    assert_eq!(filter.to_query_string(), "catalog_ids=4%2C16&brand_ids=53");
+
+    let json = serde_json::to_value(&filter).unwrap();
+    assert_eq!(json["catalog_ids"], "4,16");
+    assert_eq!(json["size_ids"], serde_json::Value::Null);
+
+    let filter: Filter =
+        serde_json::from_str(r#"{"catalog_ids":"4,16","brand_ids":[53],"size_ids":null}"#).unwrap();
+    assert_eq!(filter.catalog_ids, Some(vec![4, 16]));
+    assert_eq!(filter.brand_ids, Some(vec![53]));
+    assert_eq!(filter.size_ids, None);
+    assert_eq!(filter.color_ids, None);
+
+    assert!(serde_json::from_str::<Filter>(r#"{"catalog_ids":"4,shoes"}"#).is_err());
+}
+
+#[test]
+fn test_filter_validate() {
+    let filter: Filter = Filter::builder()
+        .brand_ids([53, 0])
+        .size_ids(Vec::<i32>::new())
+        .price_from(Some(f32::NAN))
+        .price_to(Some(-1.0))
+        .build();
+
+    let err = filter.validate().unwrap_err();
+
+    assert_eq!(err.problems.len(), 4);
+    assert!(
+        err.problems
+            .contains(&FilterProblem::InvalidId("brand_ids", 0))
+    );
+    assert!(err.problems.contains(&FilterProblem::EmptyIds("size_ids")));
+    assert!(
+        err.problems
+            .contains(&FilterProblem::NegativePrice("price_to", -1.0))
+    );
+    assert!(
+        err.problems
+            .iter()
+            .any(|problem| matches!(problem, FilterProblem::NonFinitePrice("price_from", _)))
+    );
+    assert!(err.to_string().starts_with("Invalid filter: "));
+
+    // Problems are named after the query parameter
+    let filter: Filter = Filter::builder().countries_ids([-7]).build();
+
+    assert_eq!(
+        filter.validate().unwrap_err().problems,
+        vec![FilterProblem::InvalidId("country_ids", -7)]
+    );
+
+    let filter: Filter = Filter::builder()
+        .brand_ids([53])
+        .price_from(Some(10.0))
+        .price_to(Some(10.0))
+        .build();
+
+    assert_eq!(filter.validate(), Ok(()));
+    assert_eq!(Filter::builder().build().validate(), Ok(()));
+}
+
+#[test]
+fn test_price() {
+    let price: Price = "12.50 EUR".parse().unwrap();
+    assert_eq!(price, Price::new(Decimal::new(1250, 2), Currency::EUR));
+    assert_eq!(price.to_string(), "12.50 EUR");
+    assert_eq!(
+        " 3 gbp ".parse::<Price>().unwrap().currency_code,
+        Currency::GBP
+    );
+
+    assert!(matches!(
+        "12.50".parse::<Price>(),
+        Err(ParsePriceError::FormatError(_))
+    ));
+    assert!(matches!(
+        "twelve EUR".parse::<Price>(),
+        Err(ParsePriceError::AmountError(_))
+    ));
+    assert!(matches!(
+        "12.50 BTC".parse::<Price>(),
+        Err(ParsePriceError::CurrencyError(_))
+    ));
+
+    let fee: Price = "0.70 EUR".parse().unwrap();
+    let pounds: Price = "10 GBP".parse().unwrap();
+
+    assert!(price > fee);
+    assert_eq!(price.partial_cmp(&pounds), None);
+    assert_eq!(price.checked_add(&fee).unwrap().to_string(), "13.20 EUR");
+    assert_eq!(price.checked_sub(&fee).unwrap().to_string(), "11.80 EUR");
+    assert_eq!(price.checked_add(&pounds), None);
+    assert_eq!(
+        fee.checked_mul(Decimal::from(3)).unwrap().to_string(),
+        "2.10 EUR"
+    );
+    assert_eq!(
+        Price::new(Decimal::MAX, Currency::EUR).checked_mul(Decimal::TWO),
+        None
+    );
+
+    // A new currency code does not fail the listing, and is never mixed with another one
+    let json = item_json(1).replace("\"EUR\"", "\"ZZZ\"");
+    let item: Item = serde_json::from_str(&json).unwrap();
+    assert_eq!(item.price.currency_code, Currency::Unknown);
+    assert_eq!(item.price.checked_add(&item.price), None);
+    assert_eq!(item.price.partial_cmp(&item.price), None);
+
+    let item: Item = serde_json::from_str(&item_json(1)).unwrap();
+    assert_eq!(item.price, "10.0 EUR".parse().unwrap());
+
+    let json = serde_json::to_value(&item.price).unwrap();
+    assert_eq!(json["amount"], "10.0");
+    assert_eq!(json["currency_code"], "EUR");
+}
+
+#[test]
+fn test_exchange_rates() {
+    let rates = StaticRates::new(Currency::EUR)
+        .with_rate(Currency::GBP, Decimal::new(80, 2))
+        .with_rate(Currency::PLN, Decimal::new(400, 2));
+
+    assert_eq!(rates.rate(Currency::EUR, Currency::EUR), Some(Decimal::ONE));
+    assert_eq!(
+        rates.rate(Currency::GBP, Currency::PLN),
+        Some(Decimal::from(5))
+    );
+    assert_eq!(rates.rate(Currency::EUR, Currency::HUF), None);
+
+    let price: Price = "8 GBP".parse().unwrap();
+    assert_eq!(
+        rates.convert(&price, Currency::EUR).unwrap(),
+        "10 EUR".parse().unwrap()
+    );
+    assert!(matches!(
+        rates.convert(&price, Currency::SEK),
+        Err(ExchangeError::MissingRate(Currency::GBP, Currency::SEK))
+    ));
+    assert!(matches!(
+        rates.convert(&Price::new(Decimal::MAX, Currency::EUR), Currency::PLN),
+        Err(ExchangeError::Overflow(Currency::EUR, Currency::PLN))
+    ));
+    assert!(matches!(
+        rates.convert(
+            &Price::new(Decimal::ONE, Currency::Unknown),
+            Currency::Unknown
+        ),
+        Err(ExchangeError::MissingRate(
+            Currency::Unknown,
+            Currency::Unknown
+        ))
+    ));
+
+    let mut items: Vec<Item> = [(1, "12 EUR"), (2, "20 PLN"), (3, "5000 HUF"), (4, "8 GBP")]
+        .into_iter()
+        .map(|(id, price)| {
+            let mut item: Item = serde_json::from_str(&item_json(id)).unwrap();
+            item.price = price.parse().unwrap();
+            item
+        })
+        .collect();
+
+    sort_by_price(&mut items, &rates, Currency::EUR);
+    let ids: Vec<i64> = items.iter().map(|item| item.id).collect();
+    assert_eq!(ids, vec![2, 4, 1, 3]);
+    assert_eq!(
+        items[0].price_in(&rates, Currency::EUR).unwrap(),
+        "5 EUR".parse().unwrap()
+    );
+
+    let path = std::env::temp_dir().join(format!("vinted-rs-rates-{}.json", std::process::id()));
+    std::fs::write(&path, r#"{"base":"EUR","rates":{"GBP":"0.80","PLN":4}}"#).unwrap();
+
+    let mut file_rates = FileRates::load(&path).unwrap();
+    assert_eq!(file_rates.rates(), &rates);
+
+    std::fs::write(&path, r#"{"base":"EUR","rates":{"GBP":0.5}}"#).unwrap();
+    file_rates.reload().unwrap();
+    assert_eq!(
+        file_rates.rate(Currency::GBP, Currency::EUR),
+        Some(Decimal::from(2))
+    );
+
+    std::fs::write(&path, "not json").unwrap();
+    assert!(matches!(
+        file_rates.reload(),
+        Err(ExchangeError::SerdeError(_))
+    ));
+    assert_eq!(
+        file_rates.rate(Currency::GBP, Currency::EUR),
+        Some(Decimal::from(2))
+    );
+
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(
+        FileRates::load(&path),
+        Err(ExchangeError::IoError(_))
+    ));
+}
+
+#[test]
+fn test_timestamps() {
+    let items: AdvancedItems = serde_json::from_str(&advanced_item_json(1)).unwrap();
+    let item = items.item;
+
+    assert_eq!(
+        item.created_at_ts,
+        Utc.with_ymd_and_hms(2024, 5, 10, 16, 30, 0).unwrap()
+    );
+    assert_eq!(
+        item.updated_at_ts,
+        DateTime::from_timestamp(1_715_500_000, 0)
+    );
+    assert_eq!(item.user_updated_at_ts, None);
+    assert_eq!(item.user.last_loged_on_ts, None);
+    assert!(item.is_bumped());
+    assert_eq!(item.listed_at(), item.last_push_up_at.unwrap());
+    assert!(item.age() > TimeDelta::days(100));
+    assert_eq!(item.total_price(), "11.20 EUR".parse().ok());
+
+    let items: Items = serde_json::from_str(&items_json(&[1], 1, 1)).unwrap();
+    assert_eq!(
+        items.pagination.timestamp,
+        DateTime::from_timestamp(1_700_000_000, 0).unwrap()
+    );
+
+    // Serialized timestamps are read back, e.g. from a cache
+    let json = serde_json::to_string(&items).unwrap();
+    let cached: Items = serde_json::from_str(&json).unwrap();
+    assert_eq!(cached.pagination.timestamp, items.pagination.timestamp);
+
+    let pagination = |time: &str| {
+        serde_json::from_str::<Pagination>(&format!(
+            r#"{{"current_page":1,"total_pages":1,"total_entries":1,"per_page":1,"time":{time}}}"#
+        ))
+        .map(|pagination| pagination.timestamp)
+    };
+    let expected = Utc.with_ymd_and_hms(2023, 11, 14, 22, 13, 20).unwrap();
+
+    assert_eq!(pagination("1700000000000").unwrap(), expected);
+    assert_eq!(pagination(r#""1700000000""#).unwrap(), expected);
+    assert_eq!(pagination(r#""2023-11-14 22:13:20""#).unwrap(), expected);
+    assert!(pagination(r#""yesterday""#).is_err());
+    assert!(pagination("null").is_err());
+}
+
+#[test]
+fn test_item_codes() {
+    assert_eq!(ArticleStatus::try_from(6), Ok(ArticleStatus::NewTags));
+    assert_eq!(ArticleStatus::try_from(4), Ok(ArticleStatus::Satisfactory));
+    assert_eq!(ArticleStatus::try_from(5), Err(UnknownArticleStatus(5)));
+
+    for status in [
+        ArticleStatus::NewTags,
+        ArticleStatus::NewNoTags,
+        ArticleStatus::VeryGood,
+        ArticleStatus::Good,
+        ArticleStatus::Satisfactory,
+    ] {
+        let id: &str = (&status).into();
+        assert_eq!(
+            ArticleStatus::try_from(id.parse::<i32>().unwrap()),
+            Ok(status)
+        );
+    }
+
+    let mut item = serde_json::from_str::<AdvancedItems>(&advanced_item_json(1))
+        .unwrap()
+        .item;
+
+    assert_eq!(item.article_status(), None);
+
+    item.status_id = Some(2);
+    assert_eq!(item.article_status(), Some(Ok(ArticleStatus::VeryGood)));
+
+    item.status_id = Some(42);
+    assert_eq!(item.article_status(), Some(Err(UnknownArticleStatus(42))));
+}
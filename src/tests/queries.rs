@@ -1,10 +1,27 @@
 use crate::VintedWrapper;
 use crate::db::DbController;
-use crate::model::filter::{Currency, Filter};
-use crate::queries::VintedWrapperError;
+use crate::model::feedback::{FeedbackKind, Reputation};
+use crate::model::filter::brand::Brand;
+use crate::model::filter::{Currency, Filter, FilterProblem, SortBy};
+use crate::model::item::ItemStatus;
+use crate::queries::retry::parse_retry_after;
+use crate::queries::{
+    ClientCache, FanOutOptions, Host, MemorySeenStore, Quota, RateLimiter, RetryPolicy,
+    SchedulingStrategy, SeenStore, StatusChange, StatusTracker, VintedWrapperError, VintedWrappers,
+    WardrobeOptions, Watcher, new_cookie_store,
+};
 use crate::tests::DB_URI;
+use crate::tests::mock_server::{
+    MockResponse, MockServer, advanced_item_json, cookies_response, items_json, mock_wrapper,
+    mock_wrapper_without_retry, user_feedbacks_json, user_json,
+};
 use bb8_postgres::tokio_postgres::NoTls;
+use chrono::{TimeZone, Utc};
 use env_logger;
+use futures::StreamExt;
+use reqwest::{Client, StatusCode};
+use std::time::Duration;
+use tokio::time::Instant;
 
 const POOL_SIZE: u32 = 5;
 
@@ -49,6 +66,9 @@ async fn test_get_item_query_text() {
                 VintedWrapperError::CookiesError(_) => unreachable!(),
                 VintedWrapperError::SerdeError(_) => unreachable!(),
                 VintedWrapperError::ReqWestError(_) => unreachable!(),
+                VintedWrapperError::FilterError(_) => unreachable!(),
+                VintedWrapperError::EmptyWrappersError => unreachable!(),
+                VintedWrapperError::MissingHostError(_) => unreachable!(),
             }
         }
     };
@@ -60,9 +80,7 @@ async fn test_get_item_brands() {
     let db: DbController<NoTls> = DbController::new(&DB_URI, POOL_SIZE, NoTls).await.unwrap();
     let brand = db.get_brand_by_name(&String::from("Adidas")).await.unwrap();
 
-    let filter: Filter = Filter::builder()
-        .brand_ids(Some(brand.id.to_string()))
-        .build();
+    let filter: Filter = Filter::builder().brand_ids([&brand]).build();
 
     match vinted.get_items(&filter, 1, None, None, None).await {
         // Limitado el numero de elementos a 1
@@ -81,6 +99,9 @@ async fn test_get_item_brands() {
                 VintedWrapperError::CookiesError(_) => unreachable!(),
                 VintedWrapperError::SerdeError(_) => unreachable!(),
                 VintedWrapperError::ReqWestError(_) => unreachable!(),
+                VintedWrapperError::FilterError(_) => unreachable!(),
+                VintedWrapperError::EmptyWrappersError => unreachable!(),
+                VintedWrapperError::MissingHostError(_) => unreachable!(),
             }
         }
     };
@@ -92,9 +113,7 @@ async fn test_get_items_brands() {
     let db: DbController<NoTls> = DbController::new(&DB_URI, POOL_SIZE, NoTls).await.unwrap();
     let brand = db.get_brand_by_name(&String::from("Adidas")).await.unwrap();
 
-    let filter: Filter = Filter::builder()
-        .brand_ids(Some(brand.id.to_string()))
-        .build();
+    let filter: Filter = Filter::builder().brand_ids([&brand]).build();
 
     match vinted.get_items(&filter, 10, None, None, None).await {
         Ok(items) => {
@@ -110,6 +129,9 @@ async fn test_get_items_brands() {
                 VintedWrapperError::CookiesError(_) => unreachable!(),
                 VintedWrapperError::SerdeError(_) => unreachable!(),
                 VintedWrapperError::ReqWestError(_) => unreachable!(),
+                VintedWrapperError::FilterError(_) => unreachable!(),
+                VintedWrapperError::EmptyWrappersError => unreachable!(),
+                VintedWrapperError::MissingHostError(_) => unreachable!(),
             }
         }
     };
@@ -120,9 +142,7 @@ async fn test_get_items_brands() {
 async fn test_get_items_catalogs_no_db() {
     let vinted = VintedWrapper::new();
     //Woman elements
-    let filter: Filter = Filter::builder()
-        .catalog_ids(Some(String::from("1904")))
-        .build();
+    let filter: Filter = Filter::builder().catalog_ids([1904]).build();
     let substrings = vec![
         "women", "mujer", "femme", "kobiety", "donna", "moterims", "noi", "dames", "zeny", "damen",
         "femei", "mulher", "beauty", "femmes", "dam", "hombre",
@@ -150,6 +170,9 @@ async fn test_get_items_catalogs_no_db() {
                 VintedWrapperError::CookiesError(_) => unreachable!(),
                 VintedWrapperError::SerdeError(_) => unreachable!(),
                 VintedWrapperError::ReqWestError(_) => unreachable!(),
+                VintedWrapperError::FilterError(_) => unreachable!(),
+                VintedWrapperError::EmptyWrappersError => unreachable!(),
+                VintedWrapperError::MissingHostError(_) => unreachable!(),
             }
         }
     };
@@ -170,7 +193,7 @@ async fn test_get_items_by_price() {
         Ok(items) => {
             assert!(items.items.len() <= 10);
             let ok: bool = items.items.iter().all(|item| {
-                let price: f32 = item.price.amount.parse().unwrap();
+                let price: f32 = item.price.amount.try_into().unwrap();
                 price <= max && price >= min
             });
 
@@ -184,6 +207,9 @@ async fn test_get_items_by_price() {
                 VintedWrapperError::CookiesError(_) => unreachable!(),
                 VintedWrapperError::SerdeError(_) => unreachable!(),
                 VintedWrapperError::ReqWestError(_) => unreachable!(),
+                VintedWrapperError::FilterError(_) => unreachable!(),
+                VintedWrapperError::EmptyWrappersError => unreachable!(),
+                VintedWrapperError::MissingHostError(_) => unreachable!(),
             }
         }
     };
@@ -192,10 +218,10 @@ async fn test_get_items_by_price() {
 #[tokio::test]
 async fn test_get_items_by_size_no_db() {
     let vinted = VintedWrapper::new();
-    let size_id = String::from("1568");
+    let size_id = 1568;
     let size_title = String::from("XS");
 
-    let filter: Filter = Filter::builder().size_ids(Some(size_id)).build();
+    let filter: Filter = Filter::builder().size_ids([size_id]).build();
 
     match vinted.get_items(&filter, 20, None, None, None).await {
         Ok(items) => {
@@ -212,6 +238,9 @@ async fn test_get_items_by_size_no_db() {
                 VintedWrapperError::CookiesError(_) => unreachable!(),
                 VintedWrapperError::SerdeError(_) => unreachable!(),
                 VintedWrapperError::ReqWestError(_) => unreachable!(),
+                VintedWrapperError::FilterError(_) => unreachable!(),
+                VintedWrapperError::EmptyWrappersError => unreachable!(),
+                VintedWrapperError::MissingHostError(_) => unreachable!(),
             }
         }
     };
@@ -230,9 +259,7 @@ async fn test_get_items_by_size() {
         .await
         .unwrap();
 
-    let filter: Filter = Filter::builder()
-        .size_ids(Some(size.id.to_string()))
-        .build();
+    let filter: Filter = Filter::builder().size_ids([&size]).build();
 
     match vinted.get_items(&filter, 20, None, None, None).await {
         Ok(items) => {
@@ -252,6 +279,9 @@ async fn test_get_items_by_size() {
                 VintedWrapperError::CookiesError(_) => unreachable!(),
                 VintedWrapperError::SerdeError(_) => unreachable!(),
                 VintedWrapperError::ReqWestError(_) => unreachable!(),
+                VintedWrapperError::FilterError(_) => unreachable!(),
+                VintedWrapperError::EmptyWrappersError => unreachable!(),
+                VintedWrapperError::MissingHostError(_) => unreachable!(),
             }
         }
     };
@@ -262,7 +292,7 @@ async fn test_get_items_by_material() {
     let vinted = VintedWrapper::new();
     let id = 49; // Silk
 
-    let filter: Filter = Filter::builder().material_ids(Some(id.to_string())).build();
+    let filter: Filter = Filter::builder().material_ids([id]).build();
     let num: usize = 15;
 
     match vinted
@@ -280,6 +310,9 @@ async fn test_get_items_by_material() {
                 VintedWrapperError::CookiesError(_) => unreachable!(),
                 VintedWrapperError::SerdeError(_) => unreachable!(),
                 VintedWrapperError::ReqWestError(_) => unreachable!(),
+                VintedWrapperError::FilterError(_) => unreachable!(),
+                VintedWrapperError::EmptyWrappersError => unreachable!(),
+                VintedWrapperError::MissingHostError(_) => unreachable!(),
             }
         }
     };
@@ -293,7 +326,7 @@ async fn test_get_items_by_color() {
 
     //let props = calculate_color_props(hex);
 
-    let filter: Filter = Filter::builder().color_ids(Some(id.to_string())).build();
+    let filter: Filter = Filter::builder().color_ids([id]).build();
 
     let num: usize = 20;
 
@@ -312,6 +345,9 @@ async fn test_get_items_by_color() {
                 VintedWrapperError::CookiesError(_) => unreachable!(),
                 VintedWrapperError::SerdeError(_) => unreachable!(),
                 VintedWrapperError::ReqWestError(_) => unreachable!(),
+                VintedWrapperError::FilterError(_) => unreachable!(),
+                VintedWrapperError::EmptyWrappersError => unreachable!(),
+                VintedWrapperError::MissingHostError(_) => unreachable!(),
             }
         }
     };
@@ -331,10 +367,10 @@ async fn test_get_items_by_currency() {
     {
         Ok(items) => {
             assert!(items.items.len() <= num);
-            let ok: bool = items.items.iter().all(|item| {
-                let c: &str = Currency::CZK.into();
-                item.price.currency_code == c
-            });
+            let ok: bool = items
+                .items
+                .iter()
+                .all(|item| item.price.currency_code == Currency::CZK);
 
             assert!(ok);
         }
@@ -346,6 +382,9 @@ async fn test_get_items_by_currency() {
                 VintedWrapperError::CookiesError(_) => unreachable!(),
                 VintedWrapperError::SerdeError(_) => unreachable!(),
                 VintedWrapperError::ReqWestError(_) => unreachable!(),
+                VintedWrapperError::FilterError(_) => unreachable!(),
+                VintedWrapperError::EmptyWrappersError => unreachable!(),
+                VintedWrapperError::MissingHostError(_) => unreachable!(),
             }
         }
     };
@@ -359,10 +398,8 @@ async fn test_get_advanced_items() {
     let adidas = db.get_brand_by_name(&"Adidas").await.unwrap();
     let nike = db.get_brand_by_name(&"Nike").await.unwrap();
 
-    let brands = format!("{},{}", adidas.id, nike.id);
-
     let filter = Filter::builder()
-        .brand_ids(Some(brands))
+        .brand_ids([&adidas, &nike])
         .price_from(Some(15.0))
         .price_to(Some(20.0))
         .build();
@@ -386,6 +423,9 @@ async fn test_get_advanced_items() {
                                 VintedWrapperError::CookiesError(_) => unreachable!(),
                                 VintedWrapperError::SerdeError(_) => unreachable!(),
                                 VintedWrapperError::ReqWestError(_) => unreachable!(),
+                                VintedWrapperError::FilterError(_) => unreachable!(),
+                                VintedWrapperError::EmptyWrappersError => unreachable!(),
+                                VintedWrapperError::MissingHostError(_) => unreachable!(),
                             }
                         }
                     }
@@ -400,7 +440,1222 @@ async fn test_get_advanced_items() {
                 VintedWrapperError::CookiesError(_) => unreachable!(),
                 VintedWrapperError::SerdeError(_) => unreachable!(),
                 VintedWrapperError::ReqWestError(_) => unreachable!(),
+                VintedWrapperError::FilterError(_) => unreachable!(),
+                VintedWrapperError::EmptyWrappersError => unreachable!(),
+                VintedWrapperError::MissingHostError(_) => unreachable!(),
             }
         }
     };
 }
+
+#[tokio::test]
+async fn test_get_items_mock_server() {
+    let server = MockServer::with_cookies(vec![(
+        "/api/v2/catalog/items",
+        vec![MockResponse::ok(items_json(&[1, 2], 1, 1))],
+    )])
+    .await;
+
+    let vinted = mock_wrapper(Host::Fr, &server);
+
+    assert_eq!(vinted.get_base_url(), server.url());
+
+    let filter: Filter = Filter::builder()
+        .search_text(Some(String::from("shoes")))
+        .build();
+
+    let items = vinted
+        .get_items(&filter, 2, None, None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(items.items.len(), 2);
+    assert_eq!(server.hits("/"), 1);
+    assert!(server.requested("/api/v2/catalog/items?search_text=shoes&per_page=2&page=1"));
+}
+
+#[tokio::test]
+async fn test_wrappers_cookie_jars_mock_server() {
+    let routes = || {
+        vec![(
+            "/api/v2/catalog/items",
+            vec![MockResponse::ok(items_json(&[1], 1, 1))],
+        )]
+    };
+    let filter: Filter = Filter::builder().build();
+
+    // Every wrapper owns its jar, so each one has to get its own cookies
+    let server = MockServer::with_cookies(routes()).await;
+    for _ in 0..2 {
+        let vinted = mock_wrapper(Host::Es, &server);
+        vinted
+            .get_items(&filter, 1, None, None, None)
+            .await
+            .unwrap();
+        vinted
+            .get_items(&filter, 1, None, None, None)
+            .await
+            .unwrap();
+    }
+    assert_eq!(server.hits("/"), 2);
+    assert_eq!(server.hits("/api/v2/catalog/items"), 4);
+
+    // Wrappers given the same jar share their cookies
+    let server = MockServer::with_cookies(routes()).await;
+    let cookie_store = new_cookie_store();
+    for _ in 0..2 {
+        let vinted = VintedWrapper::builder()
+            .host(Host::Es)
+            .base_url(Host::Es, server.url())
+            .cookie_store(cookie_store.clone())
+            .build();
+        vinted
+            .get_items(&filter, 1, None, None, None)
+            .await
+            .unwrap();
+    }
+    assert_eq!(server.hits("/"), 1);
+}
+
+#[test]
+fn test_client_cache() {
+    let created = std::cell::Cell::new(0);
+    let get = |cache: &mut ClientCache, user_agent: &str| {
+        cache.get_or_insert_with(user_agent, || {
+            created.set(created.get() + 1);
+            Client::new()
+        });
+        created.get()
+    };
+
+    let mut cache = ClientCache::new(2);
+    assert_eq!(get(&mut cache, "a"), 1);
+    assert_eq!(get(&mut cache, "b"), 2);
+    assert_eq!(get(&mut cache, "a"), 2);
+    // "b" is the least recently used client, it is dropped for "c"
+    assert_eq!(get(&mut cache, "c"), 3);
+    assert_eq!(get(&mut cache, "a"), 3);
+    assert_eq!(get(&mut cache, "b"), 4);
+
+    let mut disabled = ClientCache::new(0);
+    assert_eq!(get(&mut disabled, "a"), 5);
+    assert_eq!(get(&mut disabled, "a"), 6);
+
+    let vinted = VintedWrapper::builder().client_cache_size(0).build();
+    assert_eq!(vinted.get_client_cache_size(), 0);
+}
+
+#[test]
+fn test_retry_policy_delay() {
+    let policy = RetryPolicy::builder()
+        .base_delay(Duration::from_millis(100))
+        .max_delay(Duration::from_millis(300))
+        .jitter(false)
+        .build();
+
+    assert_eq!(policy.delay(1, None), Duration::from_millis(100));
+    assert_eq!(policy.delay(2, None), Duration::from_millis(200));
+    assert_eq!(policy.delay(3, None), Duration::from_millis(300));
+    assert_eq!(
+        policy.delay(1, Some(Duration::from_millis(250))),
+        Duration::from_millis(250)
+    );
+    // Retry-After is clamped to the maximum delay
+    assert_eq!(
+        policy.delay(1, Some(Duration::from_secs(2))),
+        Duration::from_millis(300)
+    );
+
+    let jittered = RetryPolicy::builder()
+        .base_delay(Duration::from_millis(100))
+        .build()
+        .delay(1, None);
+    assert!(jittered >= Duration::from_millis(50) && jittered <= Duration::from_millis(100));
+}
+
+#[tokio::test]
+async fn test_retry_policy_mock_server() {
+    let server = MockServer::with_cookies(vec![(
+        "/api/v2/catalog/items",
+        vec![
+            MockResponse::status(429).header("Retry-After", "0"),
+            MockResponse::status(403),
+            MockResponse::ok(items_json(&[1], 1, 1)),
+        ],
+    )])
+    .await;
+
+    let policy = RetryPolicy::builder()
+        .base_delay(Duration::from_millis(1))
+        .build();
+    let vinted = VintedWrapper::builder()
+        .host(Host::Fr)
+        .base_url(Host::Fr, server.url())
+        .retry_policy(policy)
+        .build();
+    let filter: Filter = Filter::builder().build();
+
+    let items = vinted
+        .get_items(&filter, 1, None, None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(items.items.len(), 1);
+    assert_eq!(server.hits("/api/v2/catalog/items"), 3);
+    // Cookies are bootstrapped once and got again after the 403
+    assert_eq!(server.hits("/"), 2);
+
+    let server = MockServer::with_cookies(vec![(
+        "/api/v2/catalog/items",
+        vec![MockResponse::status(429).header("Retry-After", "7")],
+    )])
+    .await;
+    let vinted = mock_wrapper_without_retry(Host::Fr, &server);
+
+    match vinted.get_items(&filter, 1, None, None, None).await {
+        Err(VintedWrapperError::ItemError(code, retry_after, _)) => {
+            assert_eq!(code, StatusCode::TOO_MANY_REQUESTS);
+            assert_eq!(retry_after, Some(7));
+        }
+        other => panic!("Unexpected result: {:?}", other),
+    }
+    assert_eq!(server.hits("/api/v2/catalog/items"), 1);
+
+    // A Retry-After longer than the maximum delay is not waited for
+    let server = MockServer::with_cookies(vec![(
+        "/api/v2/catalog/items",
+        vec![MockResponse::status(429).header("Retry-After", "86400")],
+    )])
+    .await;
+    let vinted = mock_wrapper(Host::Fr, &server);
+
+    match vinted.get_items(&filter, 1, None, None, None).await {
+        Err(VintedWrapperError::ItemError(code, retry_after, _)) => {
+            assert_eq!(code, StatusCode::TOO_MANY_REQUESTS);
+            assert_eq!(retry_after, Some(86400));
+        }
+        other => panic!("Unexpected result: {:?}", other),
+    }
+    assert_eq!(server.hits("/api/v2/catalog/items"), 1);
+}
+
+#[tokio::test]
+async fn test_retry_non_idempotent() {
+    let policy = RetryPolicy::builder()
+        .base_delay(Duration::from_millis(1))
+        .build();
+
+    // The token refresh is a POST, a server error may come after the server handled it
+    let server = MockServer::start(vec![(
+        "/auth/token_refresh",
+        vec![MockResponse::status(503), MockResponse::ok("")],
+    )])
+    .await;
+    let vinted = VintedWrapper::builder()
+        .host(Host::Fr)
+        .base_url(Host::Fr, server.url())
+        .retry_policy(policy.clone())
+        .build();
+
+    assert!(vinted.refresh_cookies(None, None).await.is_err());
+    assert_eq!(server.hits("/auth/token_refresh"), 1);
+
+    let server = MockServer::start(vec![(
+        "/auth/token_refresh",
+        vec![
+            MockResponse::status(429).header("Retry-After", "0"),
+            MockResponse::ok(""),
+        ],
+    )])
+    .await;
+    let vinted = VintedWrapper::builder()
+        .host(Host::Fr)
+        .base_url(Host::Fr, server.url())
+        .retry_policy(policy)
+        .build();
+
+    vinted.refresh_cookies(None, None).await.unwrap();
+    assert_eq!(server.hits("/auth/token_refresh"), 2);
+}
+
+#[tokio::test]
+async fn test_get_cookies_post_fallback() {
+    let server = MockServer::start(vec![(
+        "/",
+        vec![MockResponse::status(403), cookies_response()],
+    )])
+    .await;
+    let vinted = mock_wrapper_without_retry(Host::Fr, &server);
+
+    vinted.get_cookies(None, None).await.unwrap();
+    assert_eq!(server.methods("/"), vec!["GET", "POST"]);
+}
+
+#[test]
+fn test_parse_retry_after() {
+    let now = Utc.with_ymd_and_hms(2024, 5, 10, 8, 0, 0).unwrap();
+
+    assert_eq!(parse_retry_after(" 120 ", now), Some(120));
+    assert_eq!(
+        parse_retry_after("Fri, 10 May 2024 08:01:30 GMT", now),
+        Some(90)
+    );
+    // A date in the past means the request can be retried at once
+    assert_eq!(
+        parse_retry_after("Fri, 10 May 2024 07:00:00 GMT", now),
+        Some(0)
+    );
+    assert_eq!(parse_retry_after("soon", now), None);
+}
+
+#[tokio::test]
+async fn test_reset_cookies_shared_jar_mock_server() {
+    let server = MockServer::with_cookies(vec![(
+        "/api/v2/catalog/items",
+        vec![
+            MockResponse::ok(items_json(&[1], 1, 1)),
+            MockResponse::status(403),
+            MockResponse::ok(items_json(&[1], 1, 1)),
+        ],
+    )])
+    .await;
+
+    let cookie_store = new_cookie_store();
+    let policy = RetryPolicy::builder()
+        .base_delay(Duration::from_millis(1))
+        .build();
+    let filter: Filter = Filter::builder().build();
+
+    // Same server, seen as two hosts so the jar keeps their cookies apart
+    let other_url = server.url().replace("127.0.0.1", "localhost");
+    let other = VintedWrapper::builder()
+        .host(Host::Es)
+        .base_url(Host::Es, other_url.clone())
+        .cookie_store(cookie_store.clone())
+        .build();
+    let vinted = VintedWrapper::builder()
+        .host(Host::Fr)
+        .base_url(Host::Fr, server.url())
+        .cookie_store(cookie_store.clone())
+        .retry_policy(policy)
+        .build();
+
+    other.get_items(&filter, 1, None, None, None).await.unwrap();
+    vinted
+        .get_items(&filter, 1, None, None, None)
+        .await
+        .unwrap();
+
+    // Both hosts bootstrapped once, then the 403 only got new cookies for its own host
+    assert_eq!(server.hits("/"), 3);
+
+    let other_url = url::Url::parse(&other_url).unwrap();
+    assert!(
+        cookie_store
+            .lock()
+            .unwrap()
+            .matches(&other_url)
+            .iter()
+            .any(|cookie| cookie.name() == "__cf_bm")
+    );
+
+    other.get_items(&filter, 1, None, None, None).await.unwrap();
+    assert_eq!(server.hits("/"), 3);
+}
+
+#[tokio::test]
+async fn test_rate_limiter() {
+    let limiter = RateLimiter::new(Quota::new(2, Duration::from_millis(200)))
+        .with_host_quota(Host::Uk, Quota::per_second(100));
+
+    let start = Instant::now();
+    for _ in 0..4 {
+        limiter.acquire(Host::Fr).await;
+    }
+    // Burst of 2, then one request every 100ms
+    assert!(start.elapsed() >= Duration::from_millis(200));
+
+    // Other hosts have their own budget
+    let start = Instant::now();
+    limiter.acquire(Host::Es).await;
+    limiter.acquire(Host::Uk).await;
+    assert!(start.elapsed() < Duration::from_millis(50));
+
+    let limiter =
+        RateLimiter::per_host().with_global_quota(Quota::new(1, Duration::from_millis(100)));
+    let start = Instant::now();
+    limiter.acquire(Host::Fr).await;
+    limiter.acquire(Host::Es).await;
+    assert!(start.elapsed() >= Duration::from_millis(100));
+}
+
+#[tokio::test]
+async fn test_rate_limiter_mock_server() {
+    let server = MockServer::with_cookies(vec![(
+        "/api/v2/catalog/items",
+        vec![MockResponse::ok(items_json(&[1], 1, 1))],
+    )])
+    .await;
+
+    let mut wrappers = VintedWrappers::new_with_hosts(vec![Host::Fr]);
+    wrappers.set_rate_limiter(RateLimiter::new(Quota::new(1, Duration::from_millis(100))));
+
+    let vinted = wrappers.get_wrapper(0).clone();
+    let vinted = VintedWrapper::builder()
+        .host(Host::Fr)
+        .base_url(Host::Fr, server.url())
+        .rate_limiter(vinted.get_rate_limiter().unwrap().clone())
+        .build();
+    let filter: Filter = Filter::builder().build();
+
+    let start = Instant::now();
+    vinted
+        .get_items(&filter, 1, None, None, None)
+        .await
+        .unwrap();
+    vinted
+        .get_items(&filter, 1, None, None, None)
+        .await
+        .unwrap();
+
+    // Cookie bootstrap and two fetches
+    assert!(start.elapsed() >= Duration::from_millis(200));
+    assert_eq!(server.hits("/api/v2/catalog/items"), 2);
+}
+
+#[tokio::test]
+async fn test_items_stream_mock_server() {
+    let routes = || {
+        vec![(
+            "/api/v2/catalog/items",
+            vec![
+                MockResponse::ok(items_json(&[1, 2], 1, 3)),
+                // Item 2 shifted to the second page
+                MockResponse::ok(items_json(&[2, 3], 2, 3)),
+                MockResponse::ok(items_json(&[4], 3, 3)),
+            ],
+        )]
+    };
+    let filter: Filter = Filter::builder().build();
+
+    let server = MockServer::with_cookies(routes()).await;
+    let vinted = mock_wrapper(Host::Fr, &server);
+
+    let ids: Vec<i64> = vinted
+        .items_stream(&filter, 2, None, None, None, None)
+        .map(|item| item.unwrap().id)
+        .collect()
+        .await;
+
+    assert_eq!(ids, vec![1, 2, 3, 4]);
+    assert!(server.requested("/api/v2/catalog/items?per_page=2&page=3"));
+
+    let server = MockServer::with_cookies(routes()).await;
+    let vinted = mock_wrapper(Host::Fr, &server);
+
+    let ids: Vec<i64> = vinted
+        .items_stream(&filter, 2, Some(3), None, None, None)
+        .map(|item| item.unwrap().id)
+        .collect()
+        .await;
+
+    assert_eq!(ids, vec![1, 2, 3]);
+    assert_eq!(server.hits("/api/v2/catalog/items"), 2);
+}
+
+#[test]
+fn test_memory_seen_store() {
+    let mut store = MemorySeenStore::with_capacity(2);
+
+    assert!(store.insert(1));
+    assert!(!store.insert(1));
+    assert!(store.insert(2));
+    assert!(store.insert(3));
+
+    // Oldest id is forgotten once the capacity is exceeded
+    assert!(!store.contains(1));
+    assert!(store.contains(3));
+    assert_eq!(store.len(), 2);
+}
+
+#[tokio::test]
+async fn test_watcher_mock_server() {
+    let server = MockServer::with_cookies(vec![(
+        "/api/v2/catalog/items",
+        vec![
+            MockResponse::ok(items_json(&[2, 1], 1, 1)),
+            MockResponse::status(500),
+            MockResponse::ok(items_json(&[4, 3, 2], 1, 1)),
+        ],
+    )])
+    .await;
+
+    let vinted = mock_wrapper_without_retry(Host::Fr, &server);
+    let filter: Filter = Filter::builder()
+        .search_text(Some(String::from("nike")))
+        .build();
+
+    let watcher = Watcher::builder()
+        .wrapper(vinted)
+        .filter(filter)
+        .interval(Duration::from_millis(10))
+        .build();
+
+    let results: Vec<Result<i64, VintedWrapperError>> = watcher
+        .into_stream()
+        .map(|item| item.map(|item| item.id))
+        .take(3)
+        .collect()
+        .await;
+
+    // Items of the first poll are only marked as seen, errors do not end the stream
+    assert!(matches!(
+        results[0],
+        Err(VintedWrapperError::ItemError(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            _,
+            _
+        ))
+    ));
+    assert_eq!(results[1].as_ref().unwrap(), &3);
+    assert_eq!(results[2].as_ref().unwrap(), &4);
+    assert!(
+        server
+            .requests()
+            .iter()
+            .all(|target| target == "/" || target.contains("order=newest_first"))
+    );
+}
+
+#[tokio::test]
+async fn test_watcher_shared_jar_mock_server() {
+    let server = MockServer::with_cookies(vec![(
+        "/api/v2/catalog/items",
+        vec![
+            MockResponse::ok(items_json(&[1], 1, 1)),
+            MockResponse::status(403),
+            MockResponse::ok(items_json(&[1], 1, 1)),
+        ],
+    )])
+    .await;
+
+    let cookie_store = new_cookie_store();
+    let filter: Filter = Filter::builder().build();
+
+    // Same server, seen as two hosts so the jar keeps their cookies apart
+    let other = VintedWrapper::builder()
+        .host(Host::Es)
+        .base_url(Host::Es, server.url().replace("127.0.0.1", "localhost"))
+        .cookie_store(cookie_store.clone())
+        .build();
+    let vinted = VintedWrapper::builder()
+        .host(Host::Fr)
+        .base_url(Host::Fr, server.url())
+        .cookie_store(cookie_store.clone())
+        .retry_policy(RetryPolicy::none())
+        .build();
+
+    other.get_items(&filter, 1, None, None, None).await.unwrap();
+
+    let mut watcher = Watcher::builder()
+        .wrapper(vinted)
+        .filter(filter.clone())
+        .build();
+
+    assert!(watcher.poll().await.is_err());
+    assert_eq!(server.hits("/"), 2);
+
+    // The watched host gets new cookies, the other host keeps its own
+    watcher.poll().await.unwrap();
+    other.get_items(&filter, 1, None, None, None).await.unwrap();
+    assert_eq!(server.hits("/"), 3);
+}
+
+#[tokio::test]
+async fn test_get_items_encoding_mock_server() {
+    let server = MockServer::with_cookies(vec![(
+        "/api/v2/catalog/items",
+        vec![MockResponse::ok(items_json(&[1], 1, 1))],
+    )])
+    .await;
+
+    let vinted = mock_wrapper(Host::Es, &server);
+    let filter: Filter = Filter::builder()
+        .search_text(Some(String::from("Zapatillas & botas")))
+        .build();
+
+    vinted
+        .get_items(&filter, 1, None, None, None)
+        .await
+        .unwrap();
+
+    assert!(server.requested(&format!(
+        "/api/v2/catalog/items?{}&per_page=1&page=1",
+        filter.to_query_string()
+    )));
+}
+
+#[tokio::test]
+async fn test_get_items_invalid_filter_mock_server() {
+    let server = MockServer::with_cookies(vec![(
+        "/api/v2/catalog/items",
+        vec![MockResponse::ok(items_json(&[1], 1, 1))],
+    )])
+    .await;
+
+    let vinted = mock_wrapper(Host::Fr, &server);
+    let filter: Filter = Filter::builder()
+        .price_from(Some(30.0))
+        .price_to(Some(10.0))
+        .build();
+
+    match vinted.get_items(&filter, 10, None, None, None).await {
+        Err(VintedWrapperError::FilterError(err)) => {
+            assert_eq!(err.problems, vec![FilterProblem::PriceRange(30.0, 10.0)])
+        }
+        other => panic!("Expected a filter error, got {other:?}"),
+    }
+
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn test_host_parsing() {
+    assert_eq!("fr".parse::<Host>(), Ok(Host::Fr));
+    assert_eq!("vinted.fr".parse::<Host>(), Ok(Host::Fr));
+    assert_eq!("www.vinted.co.uk".parse::<Host>(), Ok(Host::Uk));
+    assert_eq!(" WWW.Vinted.CO.UK ".parse::<Host>(), Ok(Host::Uk));
+    assert_eq!("GB".parse::<Host>(), Ok(Host::Uk));
+    assert_eq!("uk".parse::<Host>(), Ok(Host::Uk));
+    assert_eq!("us".parse::<Host>(), Ok(Host::Com));
+    assert_eq!(Host::try_from("De"), Ok(Host::De));
+    assert_eq!(Host::try_from(String::from("vinted.pl")), Ok(Host::Pl));
+
+    assert!("vinted.xyz".parse::<Host>().is_err());
+    assert!("".parse::<Host>().is_err());
+    assert_eq!(
+        "example.com".parse::<Host>().unwrap_err().to_string(),
+        "`example.com` is not a valid Vinted host"
+    );
+
+    assert_eq!(Host::from_domain("www.vinted.com"), Some(Host::Com));
+    assert_eq!(Host::from_domain("com"), None);
+
+    assert_eq!(Host::Uk.to_string(), "co.uk");
+    assert_eq!(Host::iter().count(), 23);
+
+    for host in Host::iter() {
+        assert_eq!(host.to_string().parse::<Host>(), Ok(host));
+        assert_eq!(host.iso_code().parse::<Host>(), Ok(host));
+    }
+
+    assert_eq!(serde_json::to_string(&Host::Uk).unwrap(), r#""co.uk""#);
+    assert_eq!(
+        serde_json::from_str::<Vec<Host>>(r#"["fr","vinted.es","GB"]"#).unwrap(),
+        vec![Host::Fr, Host::Es, Host::Uk]
+    );
+    assert!(serde_json::from_str::<Host>(r#""mars""#).is_err());
+}
+
+#[test]
+fn test_host_metadata() {
+    assert_eq!(Host::Uk.currency(), Currency::GBP);
+    assert_eq!(Host::Dk.currency(), Currency::DKK);
+    assert_eq!(Host::At.language(), "de");
+    assert_eq!(Host::At.locale(), "de-AT");
+    assert_eq!(Host::Fr.country_id(), Some(16));
+    assert_eq!(Host::Fi.country_id(), None);
+    assert_eq!(Host::Be.currency(), Currency::EUR);
+    assert_eq!(Host::Be.country_id(), Some(19));
+    assert_eq!("www.vinted.be".parse::<Host>(), Ok(Host::Be));
+    assert_eq!(VintedWrappers::all_wrappers().len, Host::iter().count());
+
+    for host in Host::iter() {
+        assert_eq!(host.is_euro_host(), host.currency() == Currency::EUR);
+        assert!(host.locale().starts_with(host.language()));
+        assert!(host.locale().ends_with(&host.iso_code().to_uppercase()));
+    }
+
+    for currency in [Currency::GBP, Currency::DKK, Currency::HUF, Currency::EUR] {
+        let host: Host = currency.into();
+        assert_eq!(host.currency(), currency);
+    }
+}
+
+#[tokio::test]
+async fn test_fan_out_fetch_mock_server() {
+    let fr = MockServer::with_cookies(vec![(
+        "/api/v2/catalog/items",
+        vec![MockResponse::ok(items_json(&[1, 2], 1, 1))],
+    )])
+    .await;
+    let es = MockServer::with_cookies(vec![(
+        "/api/v2/catalog/items",
+        vec![MockResponse::ok(items_json(&[2, 3], 1, 1))],
+    )])
+    .await;
+    let it = MockServer::with_cookies(vec![(
+        "/api/v2/catalog/items",
+        vec![MockResponse::status(500)],
+    )])
+    .await;
+
+    let wrappers = VintedWrappers::new_with_wrappers(
+        [(Host::Fr, &fr), (Host::Es, &es), (Host::It, &it)]
+            .into_iter()
+            .map(|(host, server)| mock_wrapper_without_retry(host, server))
+            .collect(),
+    );
+    let filter: Filter = Filter::builder().build();
+
+    let results = wrappers
+        .fan_out_fetch(
+            &filter,
+            &FanOutOptions::builder().num(2).concurrency(3).build(),
+            None,
+            None,
+            None,
+        )
+        .await;
+
+    let found: Vec<(i64, Host, Vec<Host>)> = results
+        .items
+        .iter()
+        .map(|found| (found.item.id, found.host, found.also_on.clone()))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            (1, Host::Fr, vec![]),
+            (2, Host::Fr, vec![Host::Es]),
+            (3, Host::Es, vec![]),
+        ]
+    );
+    assert!(!results.is_complete());
+    assert_eq!(results.errors.len(), 1);
+    assert!(matches!(
+        results.errors[0],
+        (
+            Host::It,
+            VintedWrapperError::ItemError(StatusCode::INTERNAL_SERVER_ERROR, _, _)
+        )
+    ));
+
+    let results = wrappers
+        .fan_out_fetch(
+            &filter,
+            &FanOutOptions::builder()
+                .num(2)
+                .hosts(vec![Host::Es, Host::De])
+                .concurrency(0)
+                .build(),
+            None,
+            None,
+            None,
+        )
+        .await;
+
+    assert_eq!(results.items.len(), 2);
+    assert!(results.items.iter().all(|found| found.host == Host::Es));
+    // No wrapper of the set targets De
+    assert!(!results.is_complete());
+    assert!(matches!(
+        results.errors[..],
+        [(Host::De, VintedWrapperError::MissingHostError(Host::De))]
+    ));
+    assert_eq!(it.hits("/api/v2/catalog/items"), 1);
+}
+
+#[tokio::test]
+async fn test_scheduled_fetch_mock_server() {
+    let throttled = MockServer::with_cookies(vec![(
+        "/api/v2/catalog/items",
+        vec![MockResponse::status(429)],
+    )])
+    .await;
+    let healthy = MockServer::with_cookies(vec![(
+        "/api/v2/catalog/items",
+        vec![MockResponse::ok(items_json(&[1], 1, 1))],
+    )])
+    .await;
+
+    let wrappers = || {
+        VintedWrappers::new_with_wrappers(
+            [(Host::Fr, &throttled), (Host::Es, &healthy)]
+                .into_iter()
+                .map(|(host, server)| mock_wrapper_without_retry(host, server))
+                .collect(),
+        )
+    };
+    let filter: Filter = Filter::builder().build();
+
+    // Round robin starts with Fr, which fails over to Es and then cools down
+    let mut round_robin = wrappers();
+    for _ in 0..3 {
+        let (host, items) = round_robin
+            .scheduled_fetch(&filter, 1, None, None, None)
+            .await
+            .unwrap();
+        assert_eq!(host, Host::Es);
+        assert_eq!(items.items.len(), 1);
+    }
+    assert_eq!(throttled.hits("/api/v2/catalog/items"), 1);
+
+    let health = round_robin.health();
+    assert_eq!(health[0].host, Host::Fr);
+    assert_eq!(health[0].consecutive_failures, 1);
+    assert!(!health[0].is_available());
+    assert!(health[1].is_available());
+
+    // Without cool-down the throttled host is tried again when its turn comes
+    round_robin.set_cool_down(Duration::ZERO);
+    for _ in 0..2 {
+        round_robin
+            .scheduled_fetch(&filter, 1, None, None, None)
+            .await
+            .unwrap();
+    }
+    assert_eq!(throttled.hits("/api/v2/catalog/items"), 2);
+    assert_eq!(round_robin.health()[0].consecutive_failures, 2);
+
+    // Hosts never throttled go first, even when the others are not cooling down
+    round_robin.set_strategy(SchedulingStrategy::LeastRecentlyThrottled);
+    round_robin
+        .scheduled_fetch(&filter, 1, None, None, None)
+        .await
+        .unwrap();
+    assert_eq!(throttled.hits("/api/v2/catalog/items"), 2);
+
+    // Errors that are not caused by the host are not retried on another one
+    let invalid: Filter = Filter::builder().price_from(Some(-1.0)).build();
+    assert!(matches!(
+        wrappers()
+            .scheduled_fetch(&invalid, 1, None, None, None)
+            .await,
+        Err(VintedWrapperError::FilterError(_))
+    ));
+    assert_eq!(throttled.hits("/api/v2/catalog/items"), 2);
+
+    assert!(matches!(
+        VintedWrappers::new_with_wrappers(vec![])
+            .scheduled_fetch(&filter, 1, None, None, None)
+            .await,
+        Err(VintedWrapperError::EmptyWrappersError)
+    ));
+}
+
+#[tokio::test]
+async fn test_get_user_mock_server() {
+    let server = MockServer::with_cookies(vec![
+        ("/api/v2/users/42", vec![MockResponse::ok(user_json(42))]),
+        (
+            "/api/v2/wardrobe/42/items",
+            vec![MockResponse::ok(items_json(&[1, 2, 3], 1, 2))],
+        ),
+    ])
+    .await;
+
+    let vinted = mock_wrapper(Host::Fr, &server);
+
+    let profile = vinted.get_user(42, None, None, None).await.unwrap();
+
+    assert_eq!(profile.login, "seller42");
+    assert_eq!(profile.feedback_count, 20);
+    assert_eq!(profile.item_count, 3);
+    assert_eq!(profile.city.as_deref(), Some("Paris"));
+    assert!((profile.rating() - 4.5).abs() < f32::EPSILON);
+    assert!(profile.verification.email.valid);
+    assert!(profile.verification.is_verified());
+    assert!(profile.last_loged_on_ts.is_some());
+
+    let items = vinted
+        .get_user_items(
+            42,
+            &WardrobeOptions::builder().per_page(3).build(),
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(items.items.len(), 3);
+    assert_eq!(items.pagination.total_pages, 2);
+    assert!(server.requested("/api/v2/wardrobe/42/items?page=1&per_page=3&order=newest_first"));
+
+    vinted
+        .get_user_items(
+            42,
+            &WardrobeOptions::builder()
+                .per_page(3)
+                .page(2)
+                .order(SortBy::PriceAscendant)
+                .build(),
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    assert!(
+        server.requested("/api/v2/wardrobe/42/items?page=2&per_page=3&order=price_low_to_high")
+    );
+
+    match vinted.get_user(7, None, None, None).await {
+        Err(VintedWrapperError::ItemError(code, _, _)) => assert_eq!(code, StatusCode::NOT_FOUND),
+        other => panic!("expected a not found error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_get_user_feedback_mock_server() {
+    let server = MockServer::with_cookies(vec![
+        ("/api/v2/users/42", vec![MockResponse::ok(user_json(42))]),
+        (
+            "/api/v2/user_feedbacks",
+            vec![MockResponse::ok(user_feedbacks_json(&[5, 4, 3, 1, 5]))],
+        ),
+    ])
+    .await;
+
+    let vinted = mock_wrapper(Host::Fr, &server);
+
+    let page = vinted
+        .get_user_feedback(42, 20, 1, None, None, None)
+        .await
+        .unwrap();
+
+    assert!(server.requested("/api/v2/user_feedbacks?user_id=42&page=1&per_page=20&by=all"));
+
+    assert_eq!(page.feedback.len(), 5);
+    assert_eq!(page.feedback[0].kind(), FeedbackKind::Member);
+    assert_eq!(page.feedback[0].comment.as_deref(), Some("Review 0"));
+    assert_eq!(
+        page.feedback[0]
+            .author
+            .as_ref()
+            .map(|author| author.login.as_str()),
+        Some("buyer0")
+    );
+    assert_eq!(page.feedback[4].kind(), FeedbackKind::System);
+
+    let reputation = page.reputation();
+
+    assert_eq!(reputation.count, 5);
+    assert_eq!(reputation.positive, 3);
+    assert_eq!(reputation.neutral, 1);
+    assert_eq!(reputation.negative, 1);
+    assert!((reputation.average_rating - 3.6).abs() < 1e-6);
+
+    let profile = vinted.get_user(42, None, None, None).await.unwrap();
+    let reputation = Reputation::from(&profile);
+
+    assert_eq!(reputation.count, 20);
+    assert!((reputation.positive_share() - 0.9).abs() < 1e-6);
+}
+
+#[tokio::test]
+async fn test_related_items_mock_server() {
+    let server = MockServer::with_cookies(vec![
+        (
+            "/api/v2/items/5/similar_items",
+            vec![MockResponse::ok(items_json(&[5, 6, 7, 8], 1, 1))],
+        ),
+        (
+            "/api/v2/items/5",
+            vec![MockResponse::ok(advanced_item_json(5))],
+        ),
+        (
+            "/api/v2/wardrobe/1/items",
+            vec![MockResponse::ok(items_json(&[3, 5, 9], 1, 1))],
+        ),
+    ])
+    .await;
+
+    let vinted = mock_wrapper(Host::Fr, &server);
+
+    let similar = vinted
+        .get_similar_items(5, 2, None, None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        similar.iter().map(|item| item.id).collect::<Vec<_>>(),
+        vec![6, 7]
+    );
+
+    let more = vinted
+        .get_more_from_seller(5, 2, None, None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        more.iter().map(|item| item.id).collect::<Vec<_>>(),
+        vec![3, 9]
+    );
+    assert!(server.requested("/api/v2/wardrobe/1/items?page=1&per_page=3&order=newest_first"));
+}
+
+#[tokio::test]
+async fn test_reference_data_mock_server() {
+    let server = MockServer::with_cookies(vec![
+        (
+            "/api/v2/catalogs",
+            vec![MockResponse::ok(
+                r#"{"catalogs":[{"id":1904,"title":"Femmes","code":"WOMEN_ROOT","url":"/femmes","url_en":"/women","size_group_ids":[],"catalogs":[{"id":4,"title":"Vêtements","code":"WOMEN_CLOTHES","url":"/femmes/vetements","size_group_ids":[4],"catalogs":[{"id":12,"title":"Robes","code":"DRESSES","url":"/femmes/vetements/robes","size_group_ids":[4]}]}]}]}"#,
+            )],
+        ),
+        (
+            "/api/v2/size_groups",
+            vec![MockResponse::ok(
+                r#"{"size_groups":[{"id":4,"caption":"Lettres","sizes":[{"id":2,"title":"XS"},{"id":3,"title":"S"}]},{"id":9,"caption":"Autres","sizes":[{"id":99,"title":"Unique"}]}]}"#,
+            )],
+        ),
+        (
+            "/api/v2/brands",
+            vec![MockResponse::ok(
+                r#"{"brands":[{"id":53,"title":"Nike","url":"/brand/53-nike"}]}"#,
+            )],
+        ),
+        (
+            "/api/v2/colors",
+            vec![MockResponse::ok(
+                r##"{"colors":[{"id":1,"title":"Noir","hex":"000000"},{"id":9,"title":"Bleu","hex":"#007bc4"}]}"##,
+            )],
+        ),
+        (
+            "/api/v2/materials",
+            vec![MockResponse::ok(
+                r#"{"materials":[{"id":44,"title":"Coton"}]}"#,
+            )],
+        ),
+        (
+            "/api/v2/countries",
+            vec![MockResponse::ok(
+                r#"{"countries":[{"id":16,"title":"France","iso_code":"FR"}]}"#,
+            )],
+        ),
+    ])
+    .await;
+
+    let vinted = mock_wrapper(Host::Fr, &server);
+
+    let catalog = vinted.get_catalog(None, None, None).await.unwrap();
+
+    assert_eq!(
+        catalog
+            .categories
+            .iter()
+            .map(|category| (category.id, category.parent_id))
+            .collect::<Vec<_>>(),
+        vec![(1904, 0), (4, 1904), (12, 4)]
+    );
+    assert_eq!(catalog.categories[0].url_en, "/women");
+    assert_eq!(catalog.categories[1].url_en, "/femmes/vetements");
+    assert_eq!(catalog.tree.len(), 2);
+    assert_eq!(catalog.tree[1].parent_id, 4);
+    assert_eq!(catalog.tree[1].child_id, 12);
+
+    let sizes = vinted.get_sizes(&catalog, None, None, None).await.unwrap();
+
+    assert_eq!(sizes.len(), 3);
+    assert_eq!(sizes[0].title_fr, "XS");
+    assert_eq!(sizes[0].size_type_fr, "Lettres");
+    assert!(sizes[0].title_en.is_empty());
+    assert_eq!(sizes[0].category_id, 4);
+    assert_eq!(sizes[2].category_id, 0);
+
+    let brands = vinted
+        .get_brands(Some("nike air"), None, None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(brands[0].title, "Nike");
+    assert!(server.requested("/api/v2/brands?keyword=nike+air"));
+
+    let colors = vinted.get_colors(None, None, None).await.unwrap();
+
+    assert_eq!(colors[0].hex, "#000000");
+    assert_eq!(colors[1].hex, "#007BC4");
+
+    let materials = vinted.get_materials(None, None, None).await.unwrap();
+
+    assert_eq!(materials[0].material_fr, "Coton");
+
+    let countries = vinted.get_countries(None, None, None).await.unwrap();
+
+    assert_eq!(countries[0].iso_code, "FR");
+    assert_eq!(countries[0].flag, "🇫🇷");
+}
+
+#[tokio::test]
+async fn test_search_brands_mock_server() {
+    let server = MockServer::with_cookies(vec![
+        (
+            "/api/v2/brands",
+            vec![MockResponse::ok(
+                r#"{"brands":[{"id":14,"title":"adidas","url":"/brand/14-adidas"},{"id":2703,"title":"Adidas Originals","url":"/brand/2703-adidas-originals"},{"id":99,"title":"Kids by Adidas","url":"/brand/99-kids"}]}"#,
+            )],
+        ),
+    ])
+    .await;
+
+    let vinted = mock_wrapper(Host::Fr, &server);
+
+    let brands = vinted
+        .search_brands(" Adi", None, None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        brands.iter().map(|brand| brand.id).collect::<Vec<_>>(),
+        vec![14, 2703]
+    );
+    assert!(server.requested("/api/v2/brands?keyword=adi"));
+}
+
+#[tokio::test]
+async fn test_search_brands_fallback_mock_server() {
+    let server =
+        MockServer::with_cookies(vec![("/api/v2/brands", vec![MockResponse::status(503)])]).await;
+
+    let vinted = mock_wrapper_without_retry(Host::Fr, &server);
+    let adidas = Brand::builder()
+        .id(14)
+        .title(String::from("adidas"))
+        .url(String::from("/brand/14-adidas"))
+        .build();
+
+    let brands = vinted
+        .search_brands_or_else(
+            " Adi",
+            |prefix| {
+                assert_eq!(prefix, "Adi");
+                async { Ok::<_, ()>(vec![adidas.clone()]) }
+            },
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(brands, vec![adidas]);
+
+    // The error of the host is kept when the fallback fails too
+    match vinted
+        .search_brands_or_else("Adi", |_| async { Err(()) }, None, None, None)
+        .await
+    {
+        Err(VintedWrapperError::ItemError(code, _, _)) => {
+            assert_eq!(code, StatusCode::SERVICE_UNAVAILABLE)
+        }
+        other => panic!("Expected the host error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_search_brands_db_fallback() {
+    let server =
+        MockServer::with_cookies(vec![("/api/v2/brands", vec![MockResponse::status(503)])]).await;
+
+    let vinted = mock_wrapper_without_retry(Host::Fr, &server);
+
+    let db: DbController<NoTls> = DbController::new(&DB_URI, POOL_SIZE, NoTls).await.unwrap();
+
+    let brands = vinted
+        .search_brands_or_db("Adidas", &db, None, None, None)
+        .await
+        .unwrap();
+
+    assert!(
+        brands
+            .iter()
+            .any(|brand| brand.title.eq_ignore_ascii_case("adidas"))
+    );
+}
+
+#[tokio::test]
+async fn test_check_items_status_mock_server() {
+    let with_flag = |id: i64, flag: &str| {
+        advanced_item_json(id).replace(r#""url":"#, &format!(r#""{flag}":true,"url":"#))
+    };
+
+    let server = MockServer::with_cookies(vec![
+        (
+            "/api/v2/items/1",
+            vec![
+                MockResponse::ok(advanced_item_json(1)),
+                MockResponse::ok(with_flag(1, "is_reserved")),
+            ],
+        ),
+        (
+            "/api/v2/items/2",
+            vec![
+                MockResponse::ok(with_flag(2, "is_hidden")),
+                MockResponse::ok(with_flag(2, "is_closed")),
+            ],
+        ),
+        ("/api/v2/items/4", vec![MockResponse::status(500)]),
+    ])
+    .await;
+
+    let vinted = mock_wrapper_without_retry(Host::Fr, &server);
+
+    assert_eq!(
+        vinted.check_item_status(3, None, None, None).await.unwrap(),
+        ItemStatus::Deleted
+    );
+
+    let mut tracker = StatusTracker::new();
+
+    let report = vinted
+        .check_items_status(&mut tracker, &[1, 2, 3, 4], 4, None, None, None)
+        .await;
+
+    assert_eq!(
+        report.changes,
+        vec![
+            StatusChange {
+                item_id: 1,
+                previous: None,
+                current: ItemStatus::Active
+            },
+            StatusChange {
+                item_id: 2,
+                previous: None,
+                current: ItemStatus::Hidden
+            },
+            StatusChange {
+                item_id: 3,
+                previous: None,
+                current: ItemStatus::Deleted
+            },
+        ]
+    );
+    assert_eq!(report.errors.len(), 1);
+    assert_eq!(report.errors[0].0, 4);
+
+    let report = vinted
+        .check_items_status(&mut tracker, &[1, 2, 3], 4, None, None, None)
+        .await;
+
+    assert_eq!(
+        report.changes,
+        vec![
+            StatusChange {
+                item_id: 1,
+                previous: Some(ItemStatus::Active),
+                current: ItemStatus::Reserved
+            },
+            StatusChange {
+                item_id: 2,
+                previous: Some(ItemStatus::Hidden),
+                current: ItemStatus::Closed
+            },
+        ]
+    );
+    assert!(report.is_complete());
+    assert_eq!(tracker.len(), 3);
+    assert_eq!(server.hits("/"), 1);
+}